@@ -9,12 +9,11 @@ fn new_v4(c: &mut Criterion) {
     let mut group = c.benchmark_group("new_v4");
     group.throughput(Throughput::Elements(1));
 
-    group.bench_function("Nuuid", |b| b.iter(|| Uuid::new_v4_rng(&mut rng)));
-
-    // NOTE: This uses thread_rng, whereas our new_v4 is new each time.
-    // So our bench above uses new_v4_rng, which is local and seeded once at the
-    // start by OsRng, similar to thread_rng.
+    // Both of these use a thread local RNG, seeded by OsRng.
+    group.bench_function("Nuuid", |b| b.iter(Uuid::new_v4));
     group.bench_function("Uuid_", |b| b.iter(Uuid_::new_v4));
+
+    group.bench_function("Nuuid_rng", |b| b.iter(|| Uuid::new_v4_rng(&mut rng)));
 }
 
 fn new_v5(c: &mut Criterion) {
@@ -43,6 +42,17 @@ fn from_str(c: &mut Criterion) {
     group.bench_with_input("Uuid_", input, |b, i| b.iter(|| Uuid_::from_str(i)));
 }
 
+fn from_str_simple(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_str_simple");
+    group.throughput(Throughput::Elements(1));
+    let mut buf = [0; 32];
+    let input = Uuid::new_v4();
+    let input = input.to_str_simple(&mut buf);
+
+    group.bench_with_input("Nuuid", input, |b, i| b.iter(|| Uuid::from_str(i)));
+    group.bench_with_input("Uuid_", input, |b, i| b.iter(|| Uuid_::from_str(i)));
+}
+
 fn to_str(c: &mut Criterion) {
     let mut group = c.benchmark_group("to_str");
     group.throughput(Throughput::Elements(1));
@@ -123,5 +133,13 @@ fn inline(c: &mut Criterion) {
     // });
 }
 
-criterion_group!(benches, new_v4, new_v5, from_str, to_str, inline);
+criterion_group!(
+    benches,
+    new_v4,
+    new_v5,
+    from_str,
+    from_str_simple,
+    to_str,
+    inline
+);
 criterion_main!(benches);
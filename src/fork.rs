@@ -0,0 +1,87 @@
+//! Cheap fork detection for [`ReseedingRng`](crate::ReseedingRng).
+//!
+//! On Unix a `pthread_atfork` child handler counts forks,
+//! so checking for one is a single atomic load instead of a system call.
+//! Other platforms don't fork.
+#[cfg(unix)]
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Once,
+};
+
+/// Number of times this process, or its ancestors, have forked.
+#[cfg(unix)]
+static FORKS: AtomicUsize = AtomicUsize::new(0);
+
+/// Registers [`child`] with `pthread_atfork`.
+#[cfg(unix)]
+static REGISTER: Once = Once::new();
+
+#[cfg(unix)]
+extern "C" {
+    fn pthread_atfork(
+        prepare: Option<unsafe extern "C" fn()>,
+        parent: Option<unsafe extern "C" fn()>,
+        child: Option<unsafe extern "C" fn()>,
+    ) -> i32;
+}
+
+/// Runs in the child after a fork.
+#[cfg(unix)]
+unsafe extern "C" fn child() {
+    FORKS.fetch_add(1, Ordering::Relaxed);
+}
+
+/// The number of forks seen so far.
+///
+/// Only forks after the first call are counted,
+/// so call this when creating state that must not survive a fork.
+#[cfg(unix)]
+#[inline]
+pub(crate) fn forks() -> usize {
+    REGISTER.call_once(|| {
+        // Safety: `child` is async-signal-safe, only touching an atomic.
+        // Registration only fails on allocation failure, in which case forks
+        // go undetected, same as without this.
+        unsafe {
+            pthread_atfork(None, None, Some(child));
+        }
+    });
+    FORKS.load(Ordering::Relaxed)
+}
+
+/// The number of forks seen so far, always `0` without `fork`.
+#[cfg(not(unix))]
+#[inline]
+pub(crate) fn forks() -> usize {
+    0
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    extern "C" {
+        fn fork() -> i32;
+        fn waitpid(pid: i32, status: *mut i32, options: i32) -> i32;
+        fn _exit(status: i32) -> !;
+    }
+
+    #[test]
+    fn counts_forks() {
+        let before = forks();
+        // Safety: The child only does an atomic load before exiting.
+        unsafe {
+            let pid = fork();
+            assert!(pid >= 0, "fork failed");
+            if pid == 0 {
+                _exit(if forks() == before + 1 { 0 } else { 1 });
+            }
+            let mut status = 0;
+            assert_eq!(waitpid(pid, &mut status, 0), pid);
+            // Exited normally with status 0.
+            assert_eq!(status, 0, "child didn't see the fork");
+        }
+        assert_eq!(forks(), before);
+    }
+}
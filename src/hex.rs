@@ -0,0 +1,262 @@
+//! Fast hex encoding and decoding for UUID's.
+//!
+//! SSE2 is used when available, which is always on `x86_64`,
+//! otherwise lookup tables are used.
+use crate::Bytes;
+
+/// Invalid entries in [`DECODE`].
+pub(crate) const INVALID: u8 = 0xFF;
+
+/// Lookup table from ASCII to hex digit values, or [`INVALID`].
+pub(crate) const DECODE: [u8; 256] = {
+    let mut table = [INVALID; 256];
+    let mut i = 0;
+    while i < 10 {
+        table[b'0' as usize + i] = i as u8;
+        i += 1;
+    }
+    let mut i = 0;
+    while i < 6 {
+        table[b'a' as usize + i] = 10 + i as u8;
+        table[b'A' as usize + i] = 10 + i as u8;
+        i += 1;
+    }
+    table
+};
+
+/// Lookup table from nibbles to lowercase ASCII hex digits.
+const LOWER: [u8; 16] = *b"0123456789abcdef";
+
+/// Lookup table from nibbles to uppercase ASCII hex digits.
+const UPPER: [u8; 16] = *b"0123456789ABCDEF";
+
+/// Encode `bytes` as 32 ASCII hex digits.
+#[inline]
+pub(crate) fn encode(bytes: &Bytes, upper: bool) -> [u8; 32] {
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ))]
+    {
+        // Safety: SSE2 is statically known to be available.
+        unsafe { encode_sse2(bytes, upper) }
+    }
+    #[cfg(not(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    )))]
+    {
+        encode_table(bytes, upper)
+    }
+}
+
+/// Encode using [`LOWER`] or [`UPPER`].
+#[cfg_attr(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ),
+    allow(dead_code)
+)]
+fn encode_table(bytes: &Bytes, upper: bool) -> [u8; 32] {
+    let table = if upper { &UPPER } else { &LOWER };
+    let mut digits = [0; 32];
+    for (pair, byte) in digits.chunks_exact_mut(2).zip(bytes) {
+        pair[0] = table[(byte >> 4) as usize];
+        pair[1] = table[(byte & 0xF) as usize];
+    }
+    digits
+}
+
+/// Encode 16 digits at a time using SSE2.
+///
+/// # Safety
+///
+/// SSE2 must be available.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse2")]
+unsafe fn encode_sse2(bytes: &Bytes, upper: bool) -> [u8; 32] {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    let v = _mm_loadu_si128(bytes.as_ptr() as *const __m128i);
+    let mask = _mm_set1_epi8(0x0F);
+    let hi = _mm_and_si128(_mm_srli_epi16(v, 4), mask);
+    let lo = _mm_and_si128(v, mask);
+    // Distance from `'9' + 1` to `'a'` or `'A'`.
+    let alpha = _mm_set1_epi8(if upper { 7 } else { 39 });
+
+    let mut digits = [0; 32];
+    for (i, nibbles) in [_mm_unpacklo_epi8(hi, lo), _mm_unpackhi_epi8(hi, lo)]
+        .iter()
+        .enumerate()
+    {
+        let is_alpha = _mm_cmpgt_epi8(*nibbles, _mm_set1_epi8(9));
+        let ascii = _mm_add_epi8(
+            _mm_add_epi8(*nibbles, _mm_set1_epi8(b'0' as i8)),
+            _mm_and_si128(is_alpha, alpha),
+        );
+        _mm_storeu_si128(digits.as_mut_ptr().add(i * 16) as *mut __m128i, ascii);
+    }
+    digits
+}
+
+/// Decode 32 ASCII hex digits, returning [`None`] if any are invalid.
+#[inline]
+pub(crate) fn decode(s: &[u8; 32]) -> Option<Bytes> {
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ))]
+    {
+        // Safety: SSE2 is statically known to be available.
+        unsafe { decode_sse2(s) }
+    }
+    #[cfg(not(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    )))]
+    {
+        decode_table(s)
+    }
+}
+
+/// Decode using [`DECODE`].
+///
+/// Invalid digits are detected all at once at the end,
+/// keeping the loop free of branches.
+#[cfg_attr(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ),
+    allow(dead_code)
+)]
+fn decode_table(s: &[u8; 32]) -> Option<Bytes> {
+    let mut raw = [0; 16];
+    let mut invalid = 0;
+    for (byte, pair) in raw.iter_mut().zip(s.chunks_exact(2)) {
+        let hi = DECODE[pair[0] as usize];
+        let lo = DECODE[pair[1] as usize];
+        invalid |= hi | lo;
+        *byte = hi << 4 | lo;
+    }
+    if invalid & 0xF0 != 0 {
+        return None;
+    }
+    Some(raw)
+}
+
+/// Decode 16 digits at a time using SSE2.
+///
+/// # Safety
+///
+/// SSE2 must be available.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse2")]
+unsafe fn decode_sse2(s: &[u8; 32]) -> Option<Bytes> {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    /// Unsigned `x < n` for each byte.
+    ///
+    /// SSE2 only has signed comparisons, so bias values into the signed range
+    /// to do `(x ^ 0x80) < (n ^ 0x80)`.
+    #[inline(always)]
+    unsafe fn below(x: __m128i, n: i8) -> __m128i {
+        let bias = _mm_set1_epi8(i8::MIN);
+        _mm_cmplt_epi8(_mm_xor_si128(x, bias), _mm_set1_epi8(n ^ i8::MIN))
+    }
+
+    let mut raw = [0; 16];
+    for (half, out) in s.chunks_exact(16).zip(raw.chunks_exact_mut(8)) {
+        let v = _mm_loadu_si128(half.as_ptr() as *const __m128i);
+
+        let digit = _mm_sub_epi8(v, _mm_set1_epi8(b'0' as i8));
+        let is_digit = below(digit, 10);
+        // Lowercase, so `A-F` and `a-f` are handled together.
+        let alpha = _mm_sub_epi8(
+            _mm_or_si128(v, _mm_set1_epi8(0x20)),
+            _mm_set1_epi8(b'a' as i8),
+        );
+        let is_alpha = below(alpha, 6);
+
+        if _mm_movemask_epi8(_mm_or_si128(is_digit, is_alpha)) != 0xFFFF {
+            return None;
+        }
+
+        let nibbles = _mm_or_si128(
+            _mm_and_si128(is_digit, digit),
+            _mm_and_si128(is_alpha, _mm_add_epi8(alpha, _mm_set1_epi8(10))),
+        );
+        // Each 16-bit lane holds the high nibble in its low byte,
+        // and the low nibble in its high byte.
+        let hi = _mm_and_si128(_mm_slli_epi16(nibbles, 4), _mm_set1_epi16(0x00F0));
+        let lo = _mm_srli_epi16(nibbles, 8);
+        let bytes = _mm_packus_epi16(_mm_or_si128(hi, lo), _mm_setzero_si128());
+        _mm_storel_epi64(out.as_mut_ptr() as *mut __m128i, bytes);
+    }
+    Some(raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_matches_table() {
+        let bytes = [
+            0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0xFE, 0xDC, 0xBA, 0x98, 0x76, 0x54,
+            0x32, 0x10,
+        ];
+        assert_eq!(&encode(&bytes, false), b"0123456789abcdeffedcba9876543210");
+        assert_eq!(&encode(&bytes, true), b"0123456789ABCDEFFEDCBA9876543210");
+        for b in 0..=255u8 {
+            for &upper in &[false, true] {
+                let bytes = [b; 16];
+                assert_eq!(encode(&bytes, upper), encode_table(&bytes, upper));
+                assert_eq!(decode(&encode(&bytes, upper)), Some(bytes));
+            }
+        }
+    }
+
+    #[test]
+    fn decode_matches_table() {
+        let mut digits = *b"0123456789abcdefABCDEF0123456789";
+        let expected = decode_table(&digits);
+        assert!(expected.is_some());
+        assert_eq!(decode(&digits), expected);
+
+        for i in 0..digits.len() {
+            for &c in &[
+                b'g', b'G', b'/', b':', b'@', b'`', b'-', b'+', 0, 0x80, 0xFF,
+            ] {
+                let old = digits[i];
+                digits[i] = c;
+                assert_eq!(decode(&digits), None, "{:?} at {} must be invalid", c, i);
+                assert_eq!(decode_table(&digits), None);
+                digits[i] = old;
+            }
+        }
+
+        for c in 0..=255u8 {
+            let digits = [c; 32];
+            assert_eq!(
+                decode(&digits),
+                decode_table(&digits),
+                "Mismatch for {:?}",
+                c
+            );
+        }
+    }
+}
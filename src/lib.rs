@@ -1,17 +1,36 @@
 //! Create and use UUID's
 #![cfg_attr(not(any(test, feature = "std")), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
-use core::{convert::TryInto, fmt, fmt::Write as _, str::FromStr};
+use core::{convert::TryInto, fmt, str::FromStr};
 use md5::{Digest, Md5};
 use rand::prelude::*;
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
 use sha1::Sha1;
 
+/// The `rand_core` 0.6 traits and error, used by the `*_rng` methods.
+pub use rand::{CryptoRng, Error as RngError, RngCore};
+
+#[cfg(all(feature = "std", feature = "getrandom"))]
+mod fork;
+mod hex;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
+
 const UUID_STR_LENGTH: usize = 36;
+const UUID_SIMPLE_LENGTH: usize = 32;
+const UUID_BRACED_LENGTH: usize = 38;
 const UUID_URN_LENGTH: usize = 45;
 const UUID_URN: &str = "urn:uuid:";
 
+/// Time based UUID's use a 60-bit timestamp.
+const TIMESTAMP_MASK: u64 = 0x0FFF_FFFF_FFFF_FFFF;
+
+/// Unix time based UUID's use a 48-bit timestamp.
+const UNIX_TIMESTAMP_MASK: u64 = 0xFFFF_FFFF_FFFF;
+
+/// Number of 100-nanosecond intervals between the Gregorian and Unix epochs.
+const GREGORIAN_UNIX_OFFSET: i64 = 0x01B2_1DD2_1381_4000;
+
 /// The predefined DNS namespace, 6ba7b810-9dad-11d1-80b4-00c04fd430c8.
 pub const NAMESPACE_DNS: Uuid = Uuid::from_bytes([
     107, 167, 184, 16, 157, 173, 17, 209, 128, 180, 0, 192, 79, 212, 48, 200,
@@ -32,37 +51,67 @@ pub const NAMESPACE_X500: Uuid = Uuid::from_bytes([
     107, 167, 184, 20, 157, 173, 17, 209, 128, 180, 0, 192, 79, 212, 48, 200,
 ]);
 
-/// A 16 byte with the UUID.
-pub type Bytes = [u8; 16];
-
-/// Used to write out UUID's to a user-provided buffer.
-struct BytesWrapper<'a> {
-    bytes: &'a mut [u8],
-    offset: usize,
+/// Create a [`Uuid`] from a string literal at compile time.
+///
+/// Supports all formats [`Uuid::parse`] does,
+/// and fails to compile if the literal is invalid.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{uuid, Uuid, NAMESPACE_DNS};
+/// const DNS: Uuid = uuid!("6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+/// assert_eq!(DNS, NAMESPACE_DNS);
+/// ```
+///
+/// Invalid literals fail to compile
+///
+/// ```rust,compile_fail
+/// # use nuuid::{uuid, Uuid};
+/// const BAD: Uuid = uuid!("6ba7b810-9dad-11d1-80b4-00c04fd430cg");
+/// ```
+#[macro_export]
+macro_rules! uuid {
+    ($uuid:expr $(,)?) => {{
+        const UUID: $crate::Uuid = $crate::__parse_literal($uuid);
+        UUID
+    }};
 }
 
-impl<'a> BytesWrapper<'a> {
-    fn new(bytes: &'a mut [u8]) -> Self {
-        Self { bytes, offset: 0 }
-    }
-
-    fn into_inner(self) -> &'a mut [u8] {
-        self.bytes
+/// Implementation detail of [`uuid!`].
+#[doc(hidden)]
+pub const fn __parse_literal(s: &str) -> Uuid {
+    match Uuid::parse_const(s) {
+        Ok(uuid) => uuid,
+        Err(e) => match e.kind() {
+            ParseErrorKind::InvalidLength => panic!("invalid UUID literal: invalid length"),
+            ParseErrorKind::InvalidCharacter => panic!("invalid UUID literal: invalid character"),
+            ParseErrorKind::InvalidGroupLength => {
+                panic!("invalid UUID literal: invalid group length")
+            }
+            ParseErrorKind::MissingHyphen => panic!("invalid UUID literal: expected hyphen"),
+            ParseErrorKind::ExtraHyphen => panic!("invalid UUID literal: unexpected hyphen"),
+            ParseErrorKind::InvalidUrn => panic!("invalid UUID literal: invalid URN prefix"),
+        },
     }
 }
 
-impl<'a> fmt::Write for BytesWrapper<'a> {
-    fn write_str(&mut self, s: &str) -> fmt::Result {
-        if (self.bytes.len() - self.offset) < s.len() {
-            return Err(fmt::Error);
-        }
-        self.bytes[self.offset..][..s.len()].copy_from_slice(s.as_bytes());
-        self.offset += s.len();
-        Ok(())
-    }
-}
+/// A 16 byte with the UUID.
+pub type Bytes = [u8; 16];
 
 /// A CSPRNG suitable for generating UUID's.
+///
+/// This is the default, but any [`RngCore`] + [`CryptoRng`] can be used
+/// instead, such as a hardware TRNG.
+///
+/// # Cloning
+///
+/// Cloning an [`Rng`] copies its state, so the clone produces exactly the
+/// same output as the original, and so the same UUID's.
+/// The same applies to an [`Rng`] that exists before a process forks.
+///
+/// Use [`Rng::from_rng`] to create an independent [`Rng`] instead,
+/// and [`ReseedingRng`] to protect against forks.
 #[derive(Debug, Clone)]
 pub struct Rng(rand::rngs::StdRng);
 
@@ -79,12 +128,46 @@ impl Rng {
         Self(StdRng::from_seed(seed))
     }
 
-    /// Forward to rand's fill_bytes
+    /// Create a new Rng, seeded from `rng`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Rng;
+    /// # let mut trng = Rng::from_seed([0; 32]);
+    /// let rng = Rng::from_rng(&mut trng);
+    /// ```
+    pub fn from_rng<R: RngCore + CryptoRng>(mut rng: R) -> Self {
+        let mut seed = [0; 32];
+        rng.fill_bytes(&mut seed);
+        Self::from_seed(seed)
+    }
+}
+
+impl RngCore for Rng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    #[inline]
     fn fill_bytes(&mut self, dest: &mut [u8]) {
         self.0.fill_bytes(dest)
     }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RngError> {
+        self.0.try_fill_bytes(dest)
+    }
 }
 
+impl CryptoRng for Rng {}
+
 #[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
 #[cfg(feature = "getrandom")]
 impl Default for Rng {
@@ -94,207 +177,1163 @@ impl Default for Rng {
     }
 }
 
-/// UUID Variants
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
-pub enum Variant {
-    /// Reserved for NCS backward compatibility.
-    Ncs,
+/// A [`Rng`] that reseeds itself using getrandom.
+///
+/// The [`Rng`] is reseeded
+///
+/// - After generating `threshold` bytes.
+/// - After the process forks, with the `std` feature.
+///   On Unix this is detected using `pthread_atfork`,
+///   so forks bypassing libc, such as a raw `clone` system call, are missed.
+/// - On request, with [`ReseedingRng::reseed`].
+///
+/// Unlike [`Rng`], this doesn't implement [`Clone`],
+/// as the clone would produce the same output until reseeded.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{ReseedingRng, Uuid};
+/// let mut rng = ReseedingRng::new(1024);
+/// let uuid = Uuid::new_v4_from_rng(&mut rng);
+/// ```
+#[cfg(feature = "getrandom")]
+#[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
+#[derive(Debug)]
+pub struct ReseedingRng {
+    rng: Rng,
+    threshold: usize,
+    used: usize,
+    #[cfg(feature = "std")]
+    forks: usize,
+}
 
-    /// RFC 4122 conforming UUID's.
-    Rfc4122,
+#[cfg(feature = "getrandom")]
+impl ReseedingRng {
+    /// The default threshold, 64 KiB.
+    pub const DEFAULT_THRESHOLD: usize = 64 * 1024;
+
+    /// Create a new [`ReseedingRng`], reseeding after `threshold` bytes.
+    pub fn new(threshold: usize) -> Self {
+        Self {
+            rng: Rng::new(),
+            threshold,
+            used: 0,
+            #[cfg(feature = "std")]
+            forks: fork::forks(),
+        }
+    }
 
-    /// Reserved for legacy Microsoft backward compatibility.
-    Microsoft,
+    /// Reseed the [`Rng`] now.
+    pub fn reseed(&mut self) {
+        self.rng = Rng::new();
+        self.used = 0;
+        #[cfg(feature = "std")]
+        {
+            self.forks = fork::forks();
+        }
+    }
 
-    /// Reserved for the future.
-    Reserved,
+    /// Reseed if needed, before generating `len` bytes.
+    #[inline]
+    fn prepare(&mut self, len: usize) {
+        #[cfg(feature = "std")]
+        let forked = self.forks != fork::forks();
+        #[cfg(not(feature = "std"))]
+        let forked = false;
+        if forked || self.used >= self.threshold {
+            self.reseed();
+        }
+        self.used = self.used.saturating_add(len);
+    }
 }
 
-/// UUID Version
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
-pub enum Version {
-    /// Version 1, time based.
-    Time,
-
-    /// Version 2, DCE Security.
-    Dce,
-
-    /// Version 3, MD5 name based.
-    Md5,
+#[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
+#[cfg(feature = "getrandom")]
+impl Default for ReseedingRng {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_THRESHOLD)
+    }
+}
 
-    /// Version 4, random.
-    Random,
+#[cfg(feature = "getrandom")]
+impl RngCore for ReseedingRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.prepare(4);
+        self.rng.next_u32()
+    }
 
-    /// Version 5, SHA-1 name based.
-    Sha1,
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.prepare(8);
+        self.rng.next_u64()
+    }
 
-    /// Special case for the nil UUID.
-    Nil,
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.prepare(dest.len());
+        self.rng.fill_bytes(dest)
+    }
 
-    /// Special case for invalid UUIDs.
-    Invalid,
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RngError> {
+        self.prepare(dest.len());
+        self.rng.try_fill_bytes(dest)
+    }
 }
 
-/// Error parsing UUID
-#[derive(Debug)]
-pub struct ParseUuidError;
+#[cfg(feature = "getrandom")]
+impl CryptoRng for ReseedingRng {}
 
-impl fmt::Display for ParseUuidError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "ParseUuidError")
-    }
+#[cfg(all(feature = "std", feature = "getrandom"))]
+std::thread_local! {
+    /// The [`ReseedingRng`] behind [`ThreadRng`].
+    static THREAD_RNG: core::cell::RefCell<ReseedingRng> =
+        core::cell::RefCell::new(ReseedingRng::default());
 }
 
-#[cfg(any(test, feature = "std"))]
-impl std::error::Error for ParseUuidError {}
-
-/// Universally Unique Identifier, or UUID.
+/// Handle to a lazily seeded, thread local [`Rng`].
 ///
-/// This type is `repr(transparent)` and guaranteed to have the same layout
-/// as `[u8; 16]`.
+/// The [`Rng`] is seeded using getrandom the first time it's used
+/// on each thread, and is a [`ReseedingRng`] with the default threshold,
+/// so it's also reseeded after a fork.
 ///
-/// The various methods on `Uuid` assume each field
-/// is laid out Most Significant Byte First/MSB/Big-Endian/Network Endian.
+/// This is what [`Uuid::new_v4`] uses, and can be passed to any method
+/// taking an RNG.
 ///
-/// This type is also `serde(transparent)`, when serde is enabled.
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
-#[repr(transparent)]
-pub struct Uuid(Bytes);
-
-impl Uuid {
-    /// Set the UUID Version.
-    fn set_version(&mut self, ver: Version) {
-        // The version is in the 4 highest bits, so we only need the first byte.
-        // Clear the 4 highest bits.
-        self.0[6] &= 0x0F;
-
-        match ver {
-            Version::Time => {
-                self.0[6] |= 1u8 << 4;
-            }
-            Version::Dce => {
-                self.0[6] |= 2u8 << 4;
-            }
-            Version::Md5 => {
-                self.0[6] |= 3u8 << 4;
-            }
-            Version::Random => {
-                self.0[6] |= 4u8 << 4;
-            }
-            Version::Sha1 => {
-                self.0[6] |= 5u8 << 4;
-            }
-            Version::Nil => unreachable!("Can't set UUID to nil version"),
-            Version::Invalid => unreachable!("Can't set UUID to invalid version"),
-        }
-    }
-
-    /// Set the UUID Variant, only touching bits as specified.
-    ///
-    /// The version field has several unspecified bits, which this method
-    /// leaves alone. Legacy UUID's can thus be modified losslessly.
-    ///
-    /// When creating UUID's, these unspecified bits should always be zero by
-    /// default anyway.
-    fn set_variant(&mut self, ver: Variant) {
-        // The variant is, variably, in the 3 highest bits of `self.0[8]`.
-        match ver {
-            Variant::Ncs => {
-                // Clear the highest bit.
-                self.0[8] &= 0x7F;
-                // Set the highest bit
-                self.0[8] |= 0b1 << 7;
-            }
-            Variant::Rfc4122 => {
-                // Clear the highest 2 bits.
-                self.0[8] &= 0x3F;
-                // Set the highest 2 bits
-                self.0[8] |= 0b10 << 6;
-            }
-            Variant::Microsoft => {
-                // Clear the highest 3 bits.
-                self.0[8] &= 0x3F;
-                // Set the highest 3 bits
-                self.0[8] |= 0b110 << 5;
-            }
-            Variant::Reserved => {
-                // Clear the highest 3 bits.
-                self.0[8] &= 0x3F;
-                // Set the highest 3 bits
-                self.0[8] |= 0b111 << 5;
-            }
-        }
-    }
-
-    /// Swap the in-memory format between big-endian and mixed-endian.
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{ThreadRng, Uuid};
+/// let uuid = Uuid::new_v7(0x017F22E279B0, &mut ThreadRng::new());
+/// ```
+#[cfg(all(feature = "std", feature = "getrandom"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "getrandom"))))]
+#[derive(Debug, Clone, Default)]
+pub struct ThreadRng(core::marker::PhantomData<*const ()>);
+
+#[cfg(all(feature = "std", feature = "getrandom"))]
+impl ThreadRng {
+    /// Get a handle to the current threads [`Rng`].
     #[inline]
-    fn swap_endian(mut self) -> Self {
-        self.0[0..4].reverse();
-        self.0[4..6].reverse();
-        self.0[6..8].reverse();
-        self
+    pub fn new() -> Self {
+        Self::default()
     }
 }
 
-impl Uuid {
-    /// The special Nil UUID, where all bits are set to zero.
+#[cfg(all(feature = "std", feature = "getrandom"))]
+impl RngCore for ThreadRng {
     #[inline]
-    pub const fn nil() -> Self {
-        Uuid([0; 16])
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
     }
 
-    /// Create a UUID from bytes.
     #[inline]
-    pub const fn from_bytes(bytes: Bytes) -> Self {
-        Self(bytes)
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        THREAD_RNG.with(|rng| rng.borrow_mut().fill_bytes(dest))
     }
 
-    /// Return the UUID as it's bytes.
     #[inline]
-    pub const fn to_bytes(self) -> Bytes {
-        self.0
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RngError> {
+        self.fill_bytes(dest);
+        Ok(())
     }
+}
 
-    /// Create a UUID from mixed-endian bytes.
-    ///
-    /// # Note
+#[cfg(all(feature = "std", feature = "getrandom"))]
+impl CryptoRng for ThreadRng {}
+
+/// Clock sequence state for time based UUID's.
+///
+/// Tracks the last timestamp used, and increments the clock sequence
+/// whenever the clock goes backwards or the same timestamp is used twice,
+/// as described in RFC 4122 section 4.2.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{ClockSequence, Uuid};
+/// # let (timestamp, node) = (0x1EC9414C232AB00, [0; 6]);
+/// let mut ctx = ClockSequence::new(0x33C8);
+/// let uuid = Uuid::new_v1(timestamp, ctx.next(timestamp), node);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClockSequence {
+    last: Option<u64>,
+    seq: u16,
+}
+
+impl ClockSequence {
+    /// Create a new [`ClockSequence`], starting at `seq`.
     ///
-    /// This is primarily for compatibility with version 2 UUID's,
-    /// which use a mixed-endian format where the
-    /// first three fields are little-endian.
+    /// Only the lowest 14 bits of `seq` are used.
     ///
-    /// Common modern use of this format is found in Microsoft GUID's
-    /// and UEFI UUID's.
+    /// The initial clock sequence should be random,
+    /// see [`ClockSequence::from_rng`].
     #[inline]
-    pub fn from_bytes_me(bytes: Bytes) -> Self {
-        Self(bytes).swap_endian()
+    pub const fn new(seq: u16) -> Self {
+        Self {
+            last: None,
+            seq: seq & 0x3FFF,
+        }
     }
 
-    /// Return the UUID as mixed-endian bytes.
-    ///
-    /// See [`Uuid::from_bytes_me`] for details.
-    #[inline]
-    pub fn to_bytes_me(self) -> Bytes {
-        self.swap_endian().to_bytes()
+    /// Create a new [`ClockSequence`], with a random starting value.
+    pub fn from_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut seq = [0; 2];
+        rng.fill_bytes(&mut seq);
+        Self::new(u16::from_be_bytes(seq))
     }
 
-    /// Returns true if the UUID is nil.
-    #[inline]
-    pub fn is_nil(self) -> bool {
-        self.0 == Self::nil().0
+    /// Get the clock sequence to use for `timestamp`.
+    ///
+    /// If `timestamp` is not later than the previous one,
+    /// the clock sequence is incremented, wrapping at 14 bits.
+    pub fn next(&mut self, timestamp: u64) -> u16 {
+        let timestamp = timestamp & TIMESTAMP_MASK;
+        if let Some(last) = self.last {
+            if timestamp <= last {
+                self.seq = self.seq.wrapping_add(1) & 0x3FFF;
+            }
+        }
+        self.last = Some(timestamp);
+        self.seq
     }
+}
 
-    /// The UUID Variant
+/// How [`UnixTimeGenerator`] keeps UUID's ordered within the same
+/// millisecond.
+///
+/// See RFC 9562 section 6.2 for details.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Monotonic {
+    /// Fixed-length dedicated counter, method 1.
     ///
-    /// # Warning
+    /// A 42-bit counter is stored in the 12 `rand_a` bits and the highest 30
+    /// `rand_b` bits, and is randomly initialized every millisecond with
+    /// its highest bit cleared. The remaining 32 bits are random.
+    Counter,
+
+    /// Monotonic random, method 2.
     ///
-    /// Many UUIDs out in the wild are incorrectly generated,
-    /// so this value can't be relied upon.
-    #[inline]
-    pub fn variant(self) -> Variant {
-        // Check the highest 3 bits
-        match (
+    /// All 74 random bits are treated as a single counter,
+    /// randomly initialized every millisecond and incremented by a random
+    /// amount between 1 and 2^32 for each UUID.
+    Random,
+}
+
+/// Generator for monotonic Version 7 UUID's.
+///
+/// Each UUID produced by a generator is guaranteed to be greater than the
+/// previous one, even within the same millisecond.
+///
+/// If the clock goes backwards, the last timestamp continues to be used
+/// until the clock catches up.
+/// If the counter overflows, the timestamp is advanced by one millisecond
+/// and the counter reinitialized.
+///
+/// The exception is at the maximum 48-bit timestamp, in the year 10889,
+/// where the timestamp saturates. Once the counter overflows there,
+/// UUID's are no longer guaranteed to be greater.
+///
+/// Any [`RngCore`] + [`CryptoRng`] can be used, defaulting to [`Rng`].
+///
+/// Like [`Rng`], this performs no locking, share it as you see fit.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{Monotonic, Rng, UnixTimeGenerator};
+/// # let seed = [0; 32];
+/// let mut gen = UnixTimeGenerator::new(Rng::from_seed(seed), Monotonic::Counter);
+/// let first = gen.next(0x017F22E279B0);
+/// let second = gen.next(0x017F22E279B0);
+/// assert!(second > first);
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnixTimeGenerator<R = Rng> {
+    rng: R,
+    method: Monotonic,
+    last: Option<u64>,
+    counter: u128,
+}
+
+impl<R: RngCore + CryptoRng> UnixTimeGenerator<R> {
+    /// Create a new [`UnixTimeGenerator`] using `rng` and `method`.
+    pub fn new(rng: R, method: Monotonic) -> Self {
+        Self {
+            rng,
+            method,
+            last: None,
+            counter: 0,
+        }
+    }
+
+    /// Create a new Version 7 UUID for `timestamp`.
+    ///
+    /// See [`Uuid::new_v7`] for details on `timestamp`.
+    pub fn next(&mut self, timestamp: u64) -> Uuid {
+        let timestamp = timestamp & UNIX_TIMESTAMP_MASK;
+        let timestamp = match self.last {
+            Some(last) if timestamp <= last => {
+                if self.increment() {
+                    last
+                } else {
+                    self.reseed();
+                    (last + 1).min(UNIX_TIMESTAMP_MASK)
+                }
+            }
+            _ => {
+                self.reseed();
+                timestamp
+            }
+        };
+        self.last = Some(timestamp);
+
+        let random = match self.method {
+            Monotonic::Counter => (self.counter << 32) | u128::from(self.random_u32()),
+            Monotonic::Random => self.counter,
+        };
+        let mut uuid = Uuid::from_bytes(
+            ((u128::from(timestamp) << 80) | ((random >> 62) << 64) | (random & ((1 << 62) - 1)))
+                .to_be_bytes(),
+        );
+        uuid.set_variant(Variant::Rfc4122);
+        uuid.set_version(Version::UnixTime);
+        uuid
+    }
+
+    /// Reinitialize the counter for a new millisecond.
+    fn reseed(&mut self) {
+        let mut bytes = [0; 16];
+        self.rng.fill_bytes(&mut bytes[6..]);
+        let random = u128::from_be_bytes(bytes);
+        self.counter = match self.method {
+            // Leave the highest bit clear to make overflow unlikely
+            Monotonic::Counter => random & ((1 << 41) - 1),
+            Monotonic::Random => random & ((1 << 74) - 1),
+        };
+    }
+
+    /// Increment the counter, returning false on overflow.
+    fn increment(&mut self) -> bool {
+        let (step, max) = match self.method {
+            Monotonic::Counter => (1, (1 << 42) - 1),
+            Monotonic::Random => (u128::from(self.random_u32()) + 1, (1 << 74) - 1),
+        };
+        if max - self.counter < step {
+            return false;
+        }
+        self.counter += step;
+        true
+    }
+
+    fn random_u32(&mut self) -> u32 {
+        let mut bytes = [0; 4];
+        self.rng.fill_bytes(&mut bytes);
+        u32::from_be_bytes(bytes)
+    }
+
+    /// Consume the generator, returning the RNG.
+    pub fn into_rng(self) -> R {
+        self.rng
+    }
+}
+
+/// The regions of a Version 8 UUID available for custom data.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum CustomRegion {
+    /// The 48 bits before the version, `custom_a`.
+    A,
+
+    /// The 12 bits between the version and variant, `custom_b`.
+    B,
+
+    /// The 62 bits after the variant, `custom_c`.
+    C,
+}
+
+impl CustomRegion {
+    /// Position of the regions least significant bit in the UUID.
+    const fn shift(self) -> u32 {
+        match self {
+            CustomRegion::A => 80,
+            CustomRegion::B => 64,
+            CustomRegion::C => 0,
+        }
+    }
+
+    /// Size of the region, in bits.
+    const fn width(self) -> u32 {
+        match self {
+            CustomRegion::A => 48,
+            CustomRegion::B => 12,
+            CustomRegion::C => 62,
+        }
+    }
+}
+
+/// A bit field within a [`CustomRegion`] of a Version 8 UUID.
+///
+/// Fields are intended to be declared as named constants,
+/// and used with [`CustomBuilder`] and [`Uuid::custom`].
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{CustomBuilder, CustomField, CustomRegion, Version};
+/// const TENANT: CustomField = CustomField::new(CustomRegion::A, 32, 16);
+/// const SHARD: CustomField = CustomField::new(CustomRegion::B, 0, 12);
+///
+/// let uuid = CustomBuilder::new().field(TENANT, 42).field(SHARD, 7).build();
+/// assert_eq!(uuid.version(), Version::Vendor);
+/// assert_eq!(uuid.custom(TENANT), 42);
+/// assert_eq!(uuid.custom(SHARD), 7);
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct CustomField {
+    region: CustomRegion,
+    offset: u32,
+    width: u32,
+}
+
+impl CustomField {
+    /// Create a new [`CustomField`] `width` bits wide, starting `offset`
+    /// bits from the least significant bit of `region`.
+    ///
+    /// # Panics
+    ///
+    /// If `width` is zero or more than 64,
+    /// or the field doesn't fit within `region`.
+    pub const fn new(region: CustomRegion, offset: u32, width: u32) -> Self {
+        assert!(width > 0 && width <= 64, "Field width must be 1 to 64 bits");
+        assert!(
+            offset + width <= region.width(),
+            "Field must fit within its region"
+        );
+        Self {
+            region,
+            offset,
+            width,
+        }
+    }
+
+    /// Position of the fields least significant bit in the UUID.
+    const fn shift(self) -> u32 {
+        self.region.shift() + self.offset
+    }
+
+    const fn mask(self) -> u64 {
+        u64::MAX >> (64 - self.width)
+    }
+}
+
+/// Builder for Version 8 UUID's from [`CustomField`]s.
+///
+/// See [`CustomField`] for an example.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct CustomBuilder(u128);
+
+impl CustomBuilder {
+    /// Create a new [`CustomBuilder`], with all custom bits set to zero.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Set `field` to `value`.
+    ///
+    /// Bits of `value` that don't fit in `field` are ignored.
+    pub fn field(mut self, field: CustomField, value: u64) -> Self {
+        let mask = u128::from(field.mask()) << field.shift();
+        self.0 &= !mask;
+        self.0 |= (u128::from(value) << field.shift()) & mask;
+        self
+    }
+
+    /// Create the Version 8 UUID.
+    #[inline]
+    pub fn build(self) -> Uuid {
+        Uuid::new_v8(self.0.to_be_bytes())
+    }
+}
+
+/// UUID Variants
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Variant {
+    /// Reserved for NCS backward compatibility.
+    Ncs,
+
+    /// RFC 4122 conforming UUID's.
+    Rfc4122,
+
+    /// Reserved for legacy Microsoft backward compatibility.
+    Microsoft,
+
+    /// Reserved for the future.
+    Reserved,
+}
+
+/// DCE Security local domains, for Version 2 UUID's.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Domain {
+    /// The local identifier is a POSIX UID.
+    Person,
+
+    /// The local identifier is a POSIX GID.
+    Group,
+
+    /// The local identifier is an organization.
+    Org,
+
+    /// Any other, site defined, domain.
+    Other(u8),
+}
+
+impl From<u8> for Domain {
+    fn from(domain: u8) -> Self {
+        match domain {
+            0 => Domain::Person,
+            1 => Domain::Group,
+            2 => Domain::Org,
+            d => Domain::Other(d),
+        }
+    }
+}
+
+impl From<Domain> for u8 {
+    fn from(domain: Domain) -> Self {
+        match domain {
+            Domain::Person => 0,
+            Domain::Group => 1,
+            Domain::Org => 2,
+            Domain::Other(d) => d,
+        }
+    }
+}
+
+/// The timestamp of a time based UUID.
+///
+/// See [`Uuid::timestamp`].
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Timestamp {
+    /// 100-nanosecond intervals since 00:00:00.00, 15 October 1582.
+    ///
+    /// Used by Version 1 and 6 UUID's.
+    Gregorian(u64),
+
+    /// Milliseconds since the Unix Epoch, 1970-01-01 00:00:00 UTC.
+    ///
+    /// Used by Version 7 UUID's.
+    Unix(u64),
+}
+
+impl Timestamp {
+    /// The timestamp as seconds and nanoseconds since the Unix Epoch.
+    ///
+    /// Gregorian timestamps before the Unix Epoch have negative seconds,
+    /// the nanoseconds are always positive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Timestamp;
+    /// assert_eq!(Timestamp::Unix(1_645_557_742_000).to_unix(), (1_645_557_742, 0));
+    /// assert_eq!(Timestamp::Gregorian(0).to_unix(), (-12_219_292_800, 0));
+    /// ```
+    pub fn to_unix(self) -> (i64, u32) {
+        match self {
+            Timestamp::Gregorian(t) => {
+                let t = (t & TIMESTAMP_MASK) as i64 - GREGORIAN_UNIX_OFFSET;
+                (
+                    t.div_euclid(10_000_000),
+                    (t.rem_euclid(10_000_000) * 100) as u32,
+                )
+            }
+            Timestamp::Unix(t) => ((t / 1000) as i64, ((t % 1000) * 1_000_000) as u32),
+        }
+    }
+
+    /// The timestamp as a [`std::time::SystemTime`].
+    ///
+    /// Returns [`None`] if the platform can't represent the time,
+    /// such as Gregorian timestamps before 1601 on Windows.
+    ///
+    /// This requires the `std` feature.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn to_system_time(self) -> Option<std::time::SystemTime> {
+        use std::time::{Duration, UNIX_EPOCH};
+        let (secs, nanos) = self.to_unix();
+        if secs >= 0 {
+            UNIX_EPOCH.checked_add(Duration::new(secs as u64, nanos))
+        } else {
+            UNIX_EPOCH
+                .checked_sub(Duration::from_secs(secs.unsigned_abs()))?
+                .checked_add(Duration::from_nanos(nanos.into()))
+        }
+    }
+}
+
+/// UUID Version
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Version {
+    /// Version 1, time based.
+    Time,
+
+    /// Version 2, DCE Security.
+    Dce,
+
+    /// Version 3, MD5 name based.
+    Md5,
+
+    /// Version 4, random.
+    Random,
+
+    /// Version 5, SHA-1 name based.
+    Sha1,
+
+    /// Version 6, reordered time based, and sortable.
+    ReorderedTime,
+
+    /// Version 7, Unix Epoch time based, and sortable.
+    UnixTime,
+
+    /// Version 8, custom vendor specific layout.
+    Vendor,
+
+    /// Special case for the nil UUID.
+    Nil,
+
+    /// Special case for the max UUID.
+    Max,
+
+    /// Special case for invalid UUIDs.
+    Invalid,
+}
+
+impl Version {
+    /// The version number, from 1 to 8.
+    ///
+    /// Returns [`None`] for [`Version::Nil`], [`Version::Max`],
+    /// and [`Version::Invalid`], which aren't real versions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Version;
+    /// assert_eq!(Version::Random.number(), Some(4));
+    /// assert_eq!(Version::Nil.number(), None);
+    /// ```
+    pub const fn number(self) -> Option<u8> {
+        match self {
+            Version::Time => Some(1),
+            Version::Dce => Some(2),
+            Version::Md5 => Some(3),
+            Version::Random => Some(4),
+            Version::Sha1 => Some(5),
+            Version::ReorderedTime => Some(6),
+            Version::UnixTime => Some(7),
+            Version::Vendor => Some(8),
+            Version::Nil | Version::Max | Version::Invalid => None,
+        }
+    }
+}
+
+/// The kind of [`ParseUuidError`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ParseErrorKind {
+    /// The input was not a supported length.
+    InvalidLength,
+
+    /// The input contained a character that isn't a hex digit or hyphen.
+    InvalidCharacter,
+
+    /// A group of hex digits was too short.
+    InvalidGroupLength,
+
+    /// A hyphen was expected, but a hex digit was found.
+    MissingHyphen,
+
+    /// A hyphen was found where it wasn't expected.
+    ExtraHyphen,
+
+    /// The input had the length of a URN, but not the `urn:uuid:` prefix.
+    InvalidUrn,
+}
+
+/// Error setting the [`Version`] of a UUID.
+///
+/// See [`Uuid::with_version`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct VersionError(Version);
+
+impl VersionError {
+    /// The version that couldn't be set.
+    #[inline]
+    pub const fn version(&self) -> Version {
+        self.0
+    }
+}
+
+impl fmt::Display for VersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "can't set UUID to {:?} version", self.0)
+    }
+}
+
+#[cfg(any(test, feature = "std"))]
+impl std::error::Error for VersionError {}
+
+/// Error parsing UUID
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseUuidError {
+    kind: ParseErrorKind,
+    index: usize,
+    found: Option<char>,
+}
+
+impl ParseUuidError {
+    #[inline]
+    const fn new(kind: ParseErrorKind, index: usize, found: Option<char>) -> Self {
+        Self { kind, index, found }
+    }
+
+    /// Error for the byte at `index` in `s`.
+    #[inline]
+    const fn at(kind: ParseErrorKind, s: &[u8], index: usize) -> Self {
+        Self::new(kind, index, Some(s[index] as char))
+    }
+
+    /// Replace the offending character with the one from `s`,
+    /// which may not be ASCII.
+    fn with_str(mut self, s: &str) -> Self {
+        if self.found.is_some() {
+            self.found = s.get(self.index..).and_then(|s| s.chars().next());
+        }
+        self
+    }
+
+    /// The kind of error.
+    #[inline]
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The byte index into the input where the error occurred.
+    ///
+    /// For [`ParseErrorKind::InvalidLength`], this is the input length.
+    #[inline]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The offending character, if any.
+    ///
+    /// This is [`None`] for [`ParseErrorKind::InvalidLength`].
+    #[inline]
+    pub const fn found(&self) -> Option<char> {
+        self.found
+    }
+}
+
+impl fmt::Display for ParseUuidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self.kind {
+            ParseErrorKind::InvalidLength => {
+                return write!(f, "invalid UUID length {}", self.index)
+            }
+            ParseErrorKind::InvalidCharacter => "invalid character",
+            ParseErrorKind::InvalidGroupLength => "invalid group length, found",
+            ParseErrorKind::MissingHyphen => "expected hyphen, found",
+            ParseErrorKind::ExtraHyphen => "unexpected hyphen",
+            ParseErrorKind::InvalidUrn => "invalid URN prefix, found",
+        };
+        match self.found {
+            Some(c) => write!(f, "{} {:?} at index {}", msg, c, self.index),
+            None => write!(f, "{} at index {}", msg, self.index),
+        }
+    }
+}
+
+#[cfg(any(test, feature = "std"))]
+impl std::error::Error for ParseUuidError {}
+
+/// Universally Unique Identifier, or UUID.
+///
+/// This type is `repr(transparent)` and guaranteed to have the same layout
+/// as `[u8; 16]`.
+///
+/// The various methods on `Uuid` assume each field
+/// is laid out Most Significant Byte First/MSB/Big-Endian/Network Endian.
+///
+/// When serde is enabled, this type serializes as the lowercase
+/// hyphenated string in human readable formats, and as 16 bytes otherwise.
+/// It deserializes from strings in any format [`Uuid::parse`] supports,
+/// bytes, and [`u128`].
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+#[repr(transparent)]
+pub struct Uuid(Bytes);
+
+impl Uuid {
+    /// Set the UUID Version.
+    ///
+    /// # Panics
+    ///
+    /// If `ver` can't be set, see [`Version::number`].
+    fn set_version(&mut self, ver: Version) {
+        let num = match ver.number() {
+            Some(num) => num,
+            None => unreachable!("Can't set UUID to {:?} version", ver),
+        };
+        // The version is in the 4 highest bits, so we only need the first byte.
+        // Clear the 4 highest bits, and set them.
+        self.0[6] &= 0x0F;
+        self.0[6] |= num << 4;
+    }
+
+    /// Set the UUID Variant, only touching bits as specified.
+    ///
+    /// The version field has several unspecified bits, which this method
+    /// leaves alone. Legacy UUID's can thus be modified losslessly.
+    ///
+    /// When creating UUID's, these unspecified bits should always be zero by
+    /// default anyway.
+    fn set_variant(&mut self, ver: Variant) {
+        // The variant is, variably, in the 3 highest bits of `self.0[8]`.
+        match ver {
+            Variant::Ncs => {
+                // Clear the highest bit.
+                self.0[8] &= 0x7F;
+            }
+            Variant::Rfc4122 => {
+                // Clear the highest 2 bits.
+                self.0[8] &= 0x3F;
+                // Set the highest 2 bits
+                self.0[8] |= 0b10 << 6;
+            }
+            Variant::Microsoft => {
+                // Clear the highest 3 bits.
+                self.0[8] &= 0x1F;
+                // Set the highest 3 bits
+                self.0[8] |= 0b110 << 5;
+            }
+            Variant::Reserved => {
+                // Clear the highest 3 bits.
+                self.0[8] &= 0x1F;
+                // Set the highest 3 bits
+                self.0[8] |= 0b111 << 5;
+            }
+        }
+    }
+
+    /// Set the timestamp fields in the Version 1 layout.
+    ///
+    /// This overwrites the version, which must be set afterwards.
+    fn set_time_v1(&mut self, timestamp: u64) {
+        let timestamp = timestamp & TIMESTAMP_MASK;
+        self.0[..4].copy_from_slice(&(timestamp as u32).to_be_bytes());
+        self.0[4..6].copy_from_slice(&((timestamp >> 32) as u16).to_be_bytes());
+        self.0[6..8].copy_from_slice(&((timestamp >> 48) as u16).to_be_bytes());
+    }
+
+    /// Get the timestamp from the Version 1 layout.
+    fn time_v1(self) -> u64 {
+        let time_low = u32::from_be_bytes(self.0[..4].try_into().unwrap());
+        let time_mid = u16::from_be_bytes(self.0[4..6].try_into().unwrap());
+        let time_hi = u16::from_be_bytes(self.0[6..8].try_into().unwrap()) & 0x0FFF;
+        u64::from(time_hi) << 48 | u64::from(time_mid) << 32 | u64::from(time_low)
+    }
+
+    /// Set the timestamp fields in the Version 6 layout.
+    ///
+    /// This overwrites the version, which must be set afterwards.
+    fn set_time_v6(&mut self, timestamp: u64) {
+        let timestamp = timestamp & TIMESTAMP_MASK;
+        self.0[..4].copy_from_slice(&((timestamp >> 28) as u32).to_be_bytes());
+        self.0[4..6].copy_from_slice(&((timestamp >> 12) as u16).to_be_bytes());
+        self.0[6..8].copy_from_slice(&((timestamp & 0x0FFF) as u16).to_be_bytes());
+    }
+
+    /// Get the timestamp from the Version 6 layout.
+    fn time_v6(self) -> u64 {
+        let time_high = u32::from_be_bytes(self.0[..4].try_into().unwrap());
+        let time_mid = u16::from_be_bytes(self.0[4..6].try_into().unwrap());
+        let time_low = u16::from_be_bytes(self.0[6..8].try_into().unwrap()) & 0x0FFF;
+        u64::from(time_high) << 28 | u64::from(time_mid) << 12 | u64::from(time_low)
+    }
+
+    /// Write the hyphenated format into `buf`.
+    #[inline]
+    fn encode_hyphenated(self, buf: &mut [u8], upper: bool) {
+        let digits = hex::encode(&self.0, upper);
+        buf[..8].copy_from_slice(&digits[..8]);
+        buf[8] = b'-';
+        buf[9..13].copy_from_slice(&digits[8..12]);
+        buf[13] = b'-';
+        buf[14..18].copy_from_slice(&digits[12..16]);
+        buf[18] = b'-';
+        buf[19..23].copy_from_slice(&digits[16..20]);
+        buf[23] = b'-';
+        buf[24..36].copy_from_slice(&digits[20..]);
+    }
+
+    /// Write the URN format into `buf`.
+    ///
+    /// The `urn:uuid:` prefix is always lowercase.
+    #[inline]
+    fn encode_urn(self, buf: &mut [u8; 45], upper: bool) {
+        buf[..UUID_URN.len()].copy_from_slice(UUID_URN.as_bytes());
+        self.encode_hyphenated(&mut buf[UUID_URN.len()..], upper);
+    }
+
+    /// Write the braced format into `buf`.
+    #[inline]
+    fn encode_braced(self, buf: &mut [u8; 38], upper: bool) {
+        buf[0] = b'{';
+        self.encode_hyphenated(&mut buf[1..], upper);
+        buf[UUID_BRACED_LENGTH - 1] = b'}';
+    }
+
+    /// Swap the in-memory format between big-endian and mixed-endian.
+    #[inline]
+    fn swap_endian(mut self) -> Self {
+        self.0[0..4].reverse();
+        self.0[4..6].reverse();
+        self.0[6..8].reverse();
+        self
+    }
+}
+
+impl Uuid {
+    /// The special Nil UUID, where all bits are set to zero.
+    #[inline]
+    pub const fn nil() -> Self {
+        Uuid([0; 16])
+    }
+
+    /// The special Max UUID, where all bits are set to one.
+    #[inline]
+    pub const fn max() -> Self {
+        Uuid([0xFF; 16])
+    }
+
+    /// Create a UUID from bytes.
+    #[inline]
+    pub const fn from_bytes(bytes: Bytes) -> Self {
+        Self(bytes)
+    }
+
+    /// Return the UUID as it's bytes.
+    #[inline]
+    pub const fn to_bytes(self) -> Bytes {
+        self.0
+    }
+
+    /// Create a UUID from mixed-endian bytes.
+    ///
+    /// # Note
+    ///
+    /// This is primarily for compatibility with version 2 UUID's,
+    /// which use a mixed-endian format where the
+    /// first three fields are little-endian.
+    ///
+    /// Common modern use of this format is found in Microsoft GUID's
+    /// and UEFI UUID's.
+    #[inline]
+    pub fn from_bytes_me(bytes: Bytes) -> Self {
+        Self(bytes).swap_endian()
+    }
+
+    /// Return the UUID as mixed-endian bytes.
+    ///
+    /// See [`Uuid::from_bytes_me`] for details.
+    #[inline]
+    pub fn to_bytes_me(self) -> Bytes {
+        self.swap_endian().to_bytes()
+    }
+
+    /// Create a UUID from a [`u128`].
+    ///
+    /// The most significant bits of `v` are the first bytes of the UUID,
+    /// the same as [`Uuid::from_bytes`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::from_u128(0x662AA7C7_7598_4D56_8BCC_A72C30F998A2);
+    /// assert_eq!(format!("{:x}", uuid), "662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+    /// ```
+    #[inline]
+    pub const fn from_u128(v: u128) -> Self {
+        Self(v.to_be_bytes())
+    }
+
+    /// Return the UUID as a [`u128`].
+    ///
+    /// See [`Uuid::from_u128`] for details.
+    #[inline]
+    pub const fn to_u128(self) -> u128 {
+        u128::from_be_bytes(self.0)
+    }
+
+    /// Create a UUID from a [`u128`] in mixed-endian.
+    ///
+    /// This is the same as [`Uuid::from_bytes_me`] on the little-endian bytes
+    /// of `v`, such as a GUID read from memory on a little-endian machine.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::from_u128_le(0xA298F9302CA7CC8B_4D56_7598_662AA7C7);
+    /// assert_eq!(format!("{:x}", uuid), "662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+    /// ```
+    #[inline]
+    pub fn from_u128_le(v: u128) -> Self {
+        Self::from_bytes_me(v.to_le_bytes())
+    }
+
+    /// Return the UUID as a mixed-endian [`u128`].
+    ///
+    /// See [`Uuid::from_u128_le`] for details.
+    #[inline]
+    pub fn to_u128_le(self) -> u128 {
+        u128::from_le_bytes(self.to_bytes_me())
+    }
+
+    /// The next UUID, treating it as a 128-bit integer.
+    ///
+    /// Returns [`None`] if this is the largest possible UUID.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::nil().successor().unwrap();
+    /// assert_eq!(uuid.to_u128(), 1);
+    /// assert_eq!(uuid.predecessor(), Some(Uuid::nil()));
+    /// ```
+    #[inline]
+    pub fn successor(self) -> Option<Self> {
+        self.to_u128().checked_add(1).map(Self::from_u128)
+    }
+
+    /// The previous UUID, treating it as a 128-bit integer.
+    ///
+    /// Returns [`None`] if this is the nil UUID.
+    #[inline]
+    pub fn predecessor(self) -> Option<Self> {
+        self.to_u128().checked_sub(1).map(Self::from_u128)
+    }
+
+    /// Create a UUID from its fields.
+    ///
+    /// `d1` is `time_low`, `d2` is `time_mid`, `d3` is `time_hi_and_version`,
+    /// and `d4` is `clock_seq_hi_and_reserved`, `clock_seq_low`, and `node`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::from_fields(
+    ///     0x662AA7C7,
+    ///     0x7598,
+    ///     0x4D56,
+    ///     &[0x8B, 0xCC, 0xA7, 0x2C, 0x30, 0xF9, 0x98, 0xA2],
+    /// );
+    /// assert_eq!(format!("{:x}", uuid), "662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+    /// ```
+    #[inline]
+    pub const fn from_fields(d1: u32, d2: u16, d3: u16, d4: &[u8; 8]) -> Self {
+        let d1 = d1.to_be_bytes();
+        let d2 = d2.to_be_bytes();
+        let d3 = d3.to_be_bytes();
+        Self([
+            d1[0], d1[1], d1[2], d1[3], d2[0], d2[1], d3[0], d3[1], d4[0], d4[1], d4[2], d4[3],
+            d4[4], d4[5], d4[6], d4[7],
+        ])
+    }
+
+    /// Return the UUID's fields.
+    ///
+    /// See [`Uuid::from_fields`] for details.
+    #[inline]
+    pub fn as_fields(&self) -> (u32, u16, u16, &[u8; 8]) {
+        (
+            u32::from_be_bytes(self.0[..4].try_into().unwrap()),
+            u16::from_be_bytes(self.0[4..6].try_into().unwrap()),
+            u16::from_be_bytes(self.0[6..8].try_into().unwrap()),
+            self.0[8..].try_into().unwrap(),
+        )
+    }
+
+    /// Create a UUID from mixed-endian fields.
+    ///
+    /// The first three fields are byte swapped,
+    /// as if read from little-endian memory.
+    ///
+    /// See [`Uuid::from_bytes_me`] and [`Uuid::from_fields`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::from_fields_le(
+    ///     0xC7A72A66,
+    ///     0x9875,
+    ///     0x564D,
+    ///     &[0x8B, 0xCC, 0xA7, 0x2C, 0x30, 0xF9, 0x98, 0xA2],
+    /// );
+    /// assert_eq!(format!("{:x}", uuid), "662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+    /// ```
+    #[inline]
+    pub const fn from_fields_le(d1: u32, d2: u16, d3: u16, d4: &[u8; 8]) -> Self {
+        Self::from_fields(d1.swap_bytes(), d2.swap_bytes(), d3.swap_bytes(), d4)
+    }
+
+    /// Return the UUID's mixed-endian fields.
+    ///
+    /// See [`Uuid::from_fields_le`] for details.
+    #[inline]
+    pub fn to_fields_le(self) -> (u32, u16, u16, [u8; 8]) {
+        let (d1, d2, d3, d4) = self.as_fields();
+        (d1.swap_bytes(), d2.swap_bytes(), d3.swap_bytes(), *d4)
+    }
+
+    /// Returns true if the UUID is nil.
+    #[inline]
+    pub const fn is_nil(self) -> bool {
+        u128::from_ne_bytes(self.0) == 0
+    }
+
+    /// Returns true if the UUID is max.
+    #[inline]
+    pub const fn is_max(self) -> bool {
+        u128::from_ne_bytes(self.0) == u128::MAX
+    }
+
+    /// The UUID Variant
+    ///
+    /// # Warning
+    ///
+    /// Many UUIDs out in the wild are incorrectly generated,
+    /// so this value can't be relied upon.
+    #[inline]
+    pub fn variant(self) -> Variant {
+        // Check the highest 3 bits
+        match (
             self.0[8] >> 7 & 1 == 1,
             self.0[8] >> 6 & 1 == 1,
             self.0[8] >> 5 & 1 == 1,
@@ -306,14 +1345,83 @@ impl Uuid {
         }
     }
 
+    /// Return the UUID with its version set to `ver`.
+    ///
+    /// Only the 4 version bits are changed, so this can be used to repair
+    /// incorrectly generated UUID's.
+    ///
+    /// # Errors
+    ///
+    /// If `ver` is [`Version::Nil`], [`Version::Max`], or [`Version::Invalid`],
+    /// which can't be set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Uuid, Version};
+    /// let uuid = Uuid::parse("662aa7c7-7598-0d56-8bcc-a72c30f998a2").unwrap();
+    /// let uuid = uuid.with_version(Version::Random).unwrap();
+    /// assert_eq!(format!("{:x}", uuid), "662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+    ///
+    /// assert!(uuid.with_version(Version::Nil).is_err());
+    /// ```
+    pub fn with_version(mut self, ver: Version) -> Result<Self, VersionError> {
+        if ver.number().is_none() {
+            return Err(VersionError(ver));
+        }
+        self.set_version(ver);
+        Ok(self)
+    }
+
+    /// Return the UUID with its variant set to `var`.
+    ///
+    /// Only the bits specified by `var` are changed,
+    /// 1 for [`Variant::Ncs`], 2 for [`Variant::Rfc4122`],
+    /// and 3 otherwise, so legacy UUID's can be modified losslessly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Uuid, Variant};
+    /// let uuid = Uuid::parse("662aa7c7-7598-4d56-0bcc-a72c30f998a2").unwrap();
+    /// let uuid = uuid.with_variant(Variant::Rfc4122);
+    /// assert_eq!(format!("{:x}", uuid), "662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+    /// ```
+    #[inline]
+    pub fn with_variant(mut self, var: Variant) -> Self {
+        self.set_variant(var);
+        self
+    }
+
     /// The UUID Version
     ///
     /// # Warning
     ///
     /// Many UUIDs out in the wild are incorrectly generated,
     /// so this value can't be relied upon.
+    ///
+    /// Only the nil and max UUID's, with all bits zero or one,
+    /// are [`Version::Nil`] and [`Version::Max`].
+    /// Any other UUID with a version of `0` or `15` is [`Version::Invalid`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Uuid, Version};
+    /// assert_eq!(Uuid::nil().version(), Version::Nil);
+    /// assert_eq!(Uuid::max().version(), Version::Max);
+    ///
+    /// let uuid = Uuid::parse("662aa7c7-7598-0d56-8bcc-a72c30f998a2").unwrap();
+    /// assert_eq!(uuid.version(), Version::Invalid);
+    /// ```
     #[inline]
     pub fn version(self) -> Version {
+        if self.is_nil() {
+            return Version::Nil;
+        }
+        if self.is_max() {
+            return Version::Max;
+        }
         // Check the highest 4 bits
         match (
             self.0[6] >> 7 & 1 == 1,
@@ -321,124 +1429,508 @@ impl Uuid {
             self.0[6] >> 5 & 1 == 1,
             self.0[6] >> 4 & 1 == 1,
         ) {
-            (false, false, false, false) => Version::Nil,
             (false, false, false, true) => Version::Time,
             (false, false, true, false) => Version::Dce,
             (false, false, true, true) => Version::Md5,
             (false, true, false, false) => Version::Random,
             (false, true, false, true) => Version::Sha1,
+            (false, true, true, false) => Version::ReorderedTime,
+            (false, true, true, true) => Version::UnixTime,
+            (true, false, false, false) => Version::Vendor,
             _ => Version::Invalid,
         }
     }
 
+    /// The timestamp of a Version 1, 6, or 7 UUID.
+    ///
+    /// Returns [`None`] for any other version.
+    /// Version 2 UUID's are not supported, since they don't have a complete
+    /// timestamp.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Timestamp, Uuid};
+    /// let uuid = Uuid::parse("017f22e2-79b0-7cc3-98c4-dc0c0c07398f").unwrap();
+    /// assert_eq!(uuid.timestamp(), Some(Timestamp::Unix(0x017F22E279B0)));
+    /// assert_eq!(uuid.timestamp().unwrap().to_unix(), (1_645_557_742, 0));
+    /// ```
+    pub fn timestamp(self) -> Option<Timestamp> {
+        match self.version() {
+            Version::Time => Some(Timestamp::Gregorian(self.time_v1())),
+            Version::ReorderedTime => Some(Timestamp::Gregorian(self.time_v6())),
+            Version::UnixTime => {
+                let mut bytes = [0; 8];
+                bytes[2..].copy_from_slice(&self.0[..6]);
+                Some(Timestamp::Unix(u64::from_be_bytes(bytes)))
+            }
+            _ => None,
+        }
+    }
+
+    /// The local domain of a Version 2 UUID.
+    ///
+    /// Returns [`None`] if this is not a Version 2 UUID.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Domain, Uuid};
+    /// let uuid = Uuid::parse("000003e8-9414-21ec-b300-9f6bdeced846").unwrap();
+    /// assert_eq!(uuid.local_domain(), Some(Domain::Person));
+    /// assert_eq!(uuid.local_id(), Some(1000));
+    /// ```
+    #[inline]
+    pub fn local_domain(self) -> Option<Domain> {
+        if self.version() != Version::Dce {
+            return None;
+        }
+        Some(Domain::from(self.0[9]))
+    }
+
+    /// The local identifier of a Version 2 UUID,
+    /// such as a POSIX UID or GID.
+    ///
+    /// Returns [`None`] if this is not a Version 2 UUID.
+    ///
+    /// See [`Uuid::local_domain`] for an example.
+    #[inline]
+    pub fn local_id(self) -> Option<u32> {
+        if self.version() != Version::Dce {
+            return None;
+        }
+        Some(u32::from_be_bytes(self.0[..4].try_into().unwrap()))
+    }
+
+    /// Get the value of `field`.
+    ///
+    /// This is intended for Version 8 UUID's,
+    /// but doesn't check the version.
+    ///
+    /// See [`CustomField`] for details.
+    #[inline]
+    pub fn custom(self, field: CustomField) -> u64 {
+        (u128::from_be_bytes(self.0) >> field.shift()) as u64 & field.mask()
+    }
+
     /// Write UUID as a lowercase ASCII string into `buf`, and returns it as a
     /// string.
     ///
-    /// # Examples
+    /// # Examples
+    ///
+    /// With an array
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// # let uuid = Uuid::new_v4();
+    /// let mut buf = [0u8; 36];
+    /// uuid.to_str(&mut buf);
+    /// ```
+    ///
+    /// With a slice
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// # use std::convert::TryInto;
+    /// # let uuid = Uuid::new_v4();
+    /// let mut buf = [0u8; 50];
+    ///
+    /// let buf: &mut [u8] = &mut buf;
+    /// uuid.to_str((&mut buf[..36]).try_into().unwrap());
+    /// ```
+    ///
+    /// With a slice, incorrectly.
+    ///
+    /// ```rust,should_panic
+    /// # use nuuid::Uuid;
+    /// # use std::convert::TryInto;
+    /// # let uuid = Uuid::new_v4();
+    /// let mut buf = [0u8; 50];
+    ///
+    /// let buf: &mut [u8] = &mut buf;
+    /// uuid.to_str(buf.try_into().unwrap());
+    /// ```
+    pub fn to_str(self, buf: &mut [u8; 36]) -> &mut str {
+        self.encode_hyphenated(buf, false);
+        core::str::from_utf8_mut(buf).expect("BUG: Invalid UTF8")
+    }
+
+    /// Write a UUID as a lowercase ASCII string into `buf`, and return it as a
+    /// string.
+    ///
+    /// For usage examples see [`Uuid::to_str`].
+    pub fn to_urn(self, buf: &mut [u8; 45]) -> &mut str {
+        self.encode_urn(buf, false);
+        core::str::from_utf8_mut(buf).expect("BUG: Invalid UTF8")
+    }
+
+    /// Write a UUID as a lowercase ASCII string into `buf`,
+    /// without hyphens, and return it as a string.
+    ///
+    /// For usage examples see [`Uuid::to_str`].
+    pub fn to_str_simple(self, buf: &mut [u8; 32]) -> &mut str {
+        *buf = hex::encode(&self.0, false);
+        core::str::from_utf8_mut(buf).expect("BUG: Invalid UTF8")
+    }
+
+    /// [`Uuid::to_str`], but uppercase.
+    pub fn to_str_upper(self, buf: &mut [u8; 36]) -> &mut str {
+        self.encode_hyphenated(buf, true);
+        core::str::from_utf8_mut(buf).expect("BUG: Invalid UTF8")
+    }
+
+    /// [`Uuid::to_urn`], but the UUID is uppercase.
+    pub fn to_urn_upper(self, buf: &mut [u8; 45]) -> &mut str {
+        self.encode_urn(buf, true);
+        core::str::from_utf8_mut(buf).expect("BUG: Invalid UTF8")
+    }
+
+    /// [`Uuid::to_str_simple`], but uppercase.
+    pub fn to_str_simple_upper(self, buf: &mut [u8; 32]) -> &mut str {
+        *buf = hex::encode(&self.0, true);
+        core::str::from_utf8_mut(buf).expect("BUG: Invalid UTF8")
+    }
+
+    /// Write a UUID as a lowercase ASCII string into `buf`,
+    /// surrounded by braces, and return it as a string.
+    ///
+    /// This is the format commonly used for Microsoft GUID's.
+    ///
+    /// For usage examples see [`Uuid::to_str`].
+    pub fn to_str_braced(self, buf: &mut [u8; 38]) -> &mut str {
+        self.encode_braced(buf, false);
+        core::str::from_utf8_mut(buf).expect("BUG: Invalid UTF8")
+    }
+
+    /// [`Uuid::to_str_braced`], but uppercase.
+    pub fn to_str_braced_upper(self, buf: &mut [u8; 38]) -> &mut str {
+        self.encode_braced(buf, true);
+        core::str::from_utf8_mut(buf).expect("BUG: Invalid UTF8")
+    }
+
+    /// Format the UUID with hyphens.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// assert_eq!(format!("{}", uuid.hyphenated()), "662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+    /// assert_eq!(
+    ///     format!("{:>40X}", uuid.hyphenated()),
+    ///     "    662AA7C7-7598-4D56-8BCC-A72C30F998A2"
+    /// );
+    /// ```
+    #[inline]
+    pub const fn hyphenated(self) -> Hyphenated {
+        Hyphenated(self)
+    }
+
+    /// Format the UUID as a URN.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// assert_eq!(
+    ///     format!("{}", uuid.urn()),
+    ///     "urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2"
+    /// );
+    /// ```
+    #[inline]
+    pub const fn urn(self) -> Urn {
+        Urn(self)
+    }
+
+    /// Format the UUID without hyphens.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// assert_eq!(format!("{}", uuid.simple()), "662aa7c775984d568bcca72c30f998a2");
+    /// assert_eq!(format!("{:X}", uuid.simple()), "662AA7C775984D568BCCA72C30F998A2");
+    /// ```
+    #[inline]
+    pub const fn simple(self) -> Simple {
+        Simple(self)
+    }
+
+    /// Format the UUID surrounded by braces.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// assert_eq!(format!("{}", uuid.braced()), "{662aa7c7-7598-4d56-8bcc-a72c30f998a2}");
+    /// assert_eq!(format!("{:X}", uuid.braced()), "{662AA7C7-7598-4D56-8BCC-A72C30F998A2}");
+    /// ```
+    #[inline]
+    pub const fn braced(self) -> Braced {
+        Braced(self)
+    }
+}
+
+impl Uuid {
+    /// Parse a [`Uuid`] from a string
+    ///
+    /// Case insensitive and supports "urn:uuid:",
+    /// the simple format without hyphens,
+    /// and the braced format used by Microsoft GUID's.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// Uuid::parse("662AA7C7-7598-4D56-8BCC-A72C30F998A2").unwrap();
+    ///
+    /// Uuid::parse("urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// Uuid::parse("urn:uuid:662AA7C7-7598-4D56-8BCC-A72C30F998A2").unwrap();
+    ///
+    /// Uuid::parse("662aa7c775984d568bcca72c30f998a2").unwrap();
+    ///
+    /// Uuid::parse("{662aa7c7-7598-4d56-8bcc-a72c30f998a2}").unwrap();
+    /// ```
+    #[inline]
+    pub fn parse(s: &str) -> Result<Self, ParseUuidError> {
+        Uuid::from_str(s)
+    }
+
+    /// [`Uuid::parse`], but from ASCII bytes.
+    ///
+    /// This avoids needing to validate the input as UTF-8 first.
+    ///
+    /// # Note
+    ///
+    /// For non-ASCII input, [`ParseUuidError::found`] is the offending
+    /// byte as a [`char`], not the full character.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse_ascii(b"662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// assert_eq!(uuid, Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap());
+    /// ```
+    #[inline]
+    pub fn parse_ascii(s: &[u8]) -> Result<Self, ParseUuidError> {
+        parse_fast(s).map(Uuid::from_bytes)
+    }
+
+    /// Parse a UUID from the start of `s`,
+    /// returning it and the number of bytes consumed.
+    ///
+    /// Unlike [`Uuid::parse_ascii`], `s` may contain trailing data.
+    ///
+    /// The format is detected from the start of `s`.
+    /// Input starting with `urn:uuid:` is a URN,
+    /// input starting with `{` is braced,
+    /// input with a hyphen after the first 8 digits is hyphenated,
+    /// and anything else is simple.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let input = b"{662aa7c7-7598-4d56-8bcc-a72c30f998a2}, more data";
+    /// let (uuid, len) = Uuid::try_parse_ascii(input).unwrap();
+    /// assert_eq!(len, 38);
+    /// assert_eq!(&input[len..], b", more data");
+    /// ```
+    pub fn try_parse_ascii(s: &[u8]) -> Result<(Self, usize), ParseUuidError> {
+        let urn = UUID_URN.as_bytes();
+        let len = if s.len() >= urn.len() && s[..urn.len()].eq_ignore_ascii_case(urn) {
+            UUID_URN_LENGTH
+        } else if s.first() == Some(&b'{') {
+            UUID_BRACED_LENGTH
+        } else if s.get(8) == Some(&b'-') {
+            UUID_STR_LENGTH
+        } else {
+            UUID_SIMPLE_LENGTH
+        };
+        if s.len() < len {
+            return Err(ParseUuidError::new(
+                ParseErrorKind::InvalidLength,
+                s.len(),
+                None,
+            ));
+        }
+        Uuid::parse_ascii(&s[..len]).map(|uuid| (uuid, len))
+    }
+
+    /// [`Uuid::parse`], but usable in `const` contexts.
+    ///
+    /// See [`uuid!`] for creating UUID constants from string literals.
+    ///
+    /// # Note
+    ///
+    /// For non-ASCII input, [`ParseUuidError::found`] is the offending
+    /// byte as a [`char`], not the full character.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// const UUID: Uuid = match Uuid::parse_const("662aa7c7-7598-4d56-8bcc-a72c30f998a2") {
+    ///     Ok(uuid) => uuid,
+    ///     Err(_) => panic!("Invalid UUID"),
+    /// };
+    /// ```
+    #[inline]
+    pub const fn parse_const(s: &str) -> Result<Self, ParseUuidError> {
+        match parse(s.as_bytes()) {
+            Ok(bytes) => Ok(Uuid::from_bytes(bytes)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Create a new Version 1(Time) UUID.
+    ///
+    /// `timestamp` is the number of 100-nanosecond intervals since
+    /// 00:00:00.00, 15 October 1582, the date of Gregorian reform to the
+    /// Christian calendar. Only the lowest 60 bits are used.
+    ///
+    /// `clock_seq` is the clock sequence, of which only the lowest 14 bits
+    /// are used. See [`ClockSequence`] for generating these.
+    ///
+    /// `node` is the 48-bit node ID, usually an IEEE 802 MAC address.
+    /// If one isn't available, or you don't want to expose it,
+    /// use random bytes with the least significant bit of the first octet,
+    /// the multicast bit, set to `1`.
+    ///
+    /// Providing the time is left to you,
+    /// so this works in `no_std` environments.
     ///
-    /// With an array
+    /// # Example
     ///
     /// ```rust
     /// # use nuuid::Uuid;
-    /// # let uuid = Uuid::new_v4();
-    /// let mut buf = [0u8; 36];
-    /// uuid.to_str(&mut buf);
+    /// let node = [0x9F, 0x6B, 0xDE, 0xCE, 0xD8, 0x46];
+    /// let uuid = Uuid::new_v1(0x1EC9414C232AB00, 0x33C8, node);
+    /// assert_eq!(format!("{:x}", uuid), "c232ab00-9414-11ec-b3c8-9f6bdeced846");
     /// ```
+    pub fn new_v1(timestamp: u64, clock_seq: u16, node: [u8; 6]) -> Self {
+        let mut uuid = Uuid::nil();
+        uuid.set_time_v1(timestamp);
+        uuid.0[8..10].copy_from_slice(&(clock_seq & 0x3FFF).to_be_bytes());
+        uuid.0[10..].copy_from_slice(&node);
+        uuid.set_variant(Variant::Rfc4122);
+        uuid.set_version(Version::Time);
+        uuid
+    }
+
+    /// Create a new Version 6(Reordered Time) UUID.
     ///
-    /// With a slice
+    /// This is the same as [`Uuid::new_v1`], except the timestamp is stored
+    /// most significant bits first, so UUID's sort by their timestamp.
+    ///
+    /// See [`Uuid::new_v1`] for details on the arguments.
+    ///
+    /// # Example
     ///
     /// ```rust
     /// # use nuuid::Uuid;
-    /// # use std::convert::TryInto;
-    /// # let uuid = Uuid::new_v4();
-    /// let mut buf = [0u8; 50];
-    ///
-    /// let buf: &mut [u8] = &mut buf;
-    /// uuid.to_str((&mut buf[..36]).try_into().unwrap());
+    /// let node = [0x9F, 0x6B, 0xDE, 0xCE, 0xD8, 0x46];
+    /// let uuid = Uuid::new_v6(0x1EC9414C232AB00, 0x33C8, node);
+    /// assert_eq!(format!("{:x}", uuid), "1ec9414c-232a-6b00-b3c8-9f6bdeced846");
     /// ```
+    pub fn new_v6(timestamp: u64, clock_seq: u16, node: [u8; 6]) -> Self {
+        let mut uuid = Uuid::nil();
+        uuid.set_time_v6(timestamp);
+        uuid.0[8..10].copy_from_slice(&(clock_seq & 0x3FFF).to_be_bytes());
+        uuid.0[10..].copy_from_slice(&node);
+        uuid.set_variant(Variant::Rfc4122);
+        uuid.set_version(Version::ReorderedTime);
+        uuid
+    }
+
+    /// Convert a Version 1 UUID to an equivalent Version 6 UUID.
     ///
-    /// With a slice, incorrectly.
+    /// The timestamp is reordered, while the clock sequence, node,
+    /// and variant are preserved exactly.
     ///
-    /// ```rust,should_panic
-    /// # use nuuid::Uuid;
-    /// # use std::convert::TryInto;
-    /// # let uuid = Uuid::new_v4();
-    /// let mut buf = [0u8; 50];
+    /// Returns [`None`] if this is not a Version 1 UUID.
     ///
-    /// let buf: &mut [u8] = &mut buf;
-    /// uuid.to_str(buf.try_into().unwrap());
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let v1 = Uuid::parse("c232ab00-9414-11ec-b3c8-9f6bdeced846").unwrap();
+    /// let v6 = v1.v1_to_v6().unwrap();
+    /// assert_eq!(format!("{:x}", v6), "1ec9414c-232a-6b00-b3c8-9f6bdeced846");
+    /// assert_eq!(v6.v6_to_v1(), Some(v1));
     /// ```
-    pub fn to_str(self, buf: &mut [u8; 36]) -> &mut str {
-        let bytes = self.to_bytes();
-        let time_low = u32::from_be_bytes(bytes[..4].try_into().unwrap());
-        let time_mid = u16::from_be_bytes(bytes[4..6].try_into().unwrap());
-        let time_hi_and_version = u16::from_be_bytes(bytes[6..8].try_into().unwrap());
-        let clock_seq_hi_and_reserved = u8::from_be_bytes(bytes[8..9].try_into().unwrap());
-        let clock_seq_low = u8::from_be_bytes(bytes[9..10].try_into().unwrap());
-        let mut node = [0; 8];
-        // Leading zeros, and last 48 bits/6 bytes
-        node[2..].copy_from_slice(&bytes[10..16]);
-        let node = u64::from_be_bytes(node);
-        let mut buf = BytesWrapper::new(buf);
-        write!(
-            buf,
-            "{:08x}-{:04x}-{:04x}-{:02x}{:02x}-{:012x}",
-            time_low, time_mid, time_hi_and_version, clock_seq_hi_and_reserved, clock_seq_low, node
-        )
-        .expect("BUG: Couldn't write UUID");
-        core::str::from_utf8_mut(buf.into_inner()).expect("BUG: Invalid UTF8")
+    pub fn v1_to_v6(self) -> Option<Self> {
+        if self.version() != Version::Time {
+            return None;
+        }
+        let mut uuid = self;
+        uuid.set_time_v6(self.time_v1());
+        uuid.set_version(Version::ReorderedTime);
+        Some(uuid)
     }
 
-    /// Write a UUID as a lowercase ASCII string into `buf`, and return it as a
-    /// string.
+    /// Convert a Version 6 UUID to an equivalent Version 1 UUID.
     ///
-    /// For usage examples see [`Uuid::to_str`].
-    pub fn to_urn(self, buf: &mut [u8; 45]) -> &mut str {
-        buf[..UUID_URN.len()].copy_from_slice(UUID_URN.as_bytes());
-        self.to_str((&mut buf[UUID_URN.len()..]).try_into().unwrap());
-        core::str::from_utf8_mut(buf).expect("BUG: Invalid UTF8")
-    }
-
-    /// [`Uuid::to_str`], but uppercase.
-    pub fn to_str_upper(self, buf: &mut [u8; 36]) -> &mut str {
-        let s = self.to_str(buf);
-        s.make_ascii_uppercase();
-        s
-    }
-
-    /// [`Uuid::to_urn`], but the UUID is uppercase.
-    pub fn to_urn_upper(self, buf: &mut [u8; 45]) -> &mut str {
-        let s = self.to_urn(buf);
-        s[UUID_URN.len()..].make_ascii_uppercase();
-        s
+    /// See [`Uuid::v1_to_v6`] for details.
+    ///
+    /// Returns [`None`] if this is not a Version 6 UUID.
+    pub fn v6_to_v1(self) -> Option<Self> {
+        if self.version() != Version::ReorderedTime {
+            return None;
+        }
+        let mut uuid = self;
+        uuid.set_time_v1(self.time_v6());
+        uuid.set_version(Version::Time);
+        Some(uuid)
     }
-}
 
-impl Uuid {
-    /// Parse a [`Uuid`] from a string
+    /// Create a new Version 2(DCE Security) UUID.
     ///
-    /// Case insensitive and supports "urn:uuid:"
+    /// This is the same as [`Uuid::new_v1`], except the lowest 32 bits of the
+    /// timestamp are replaced by `local_id`, and the lowest 8 bits of the
+    /// clock sequence are replaced by `domain`.
+    /// Only the lowest 6 bits of `clock_seq` are used.
+    ///
+    /// See [`Uuid::new_v1`] for details on the other arguments.
     ///
     /// # Example
     ///
     /// ```rust
-    /// # use nuuid::Uuid;
-    /// Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
-    /// Uuid::parse("662AA7C7-7598-4D56-8BCC-A72C30F998A2").unwrap();
-    ///
-    /// Uuid::parse("urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
-    /// Uuid::parse("urn:uuid:662AA7C7-7598-4D56-8BCC-A72C30F998A2").unwrap();
+    /// # use nuuid::{Domain, Uuid};
+    /// let node = [0x9F, 0x6B, 0xDE, 0xCE, 0xD8, 0x46];
+    /// let uuid = Uuid::new_v2(Domain::Person, 1000, 0x1EC9414C232AB00, 0x33, node);
+    /// assert_eq!(format!("{:x}", uuid), "000003e8-9414-21ec-b300-9f6bdeced846");
     /// ```
-    #[inline]
-    pub fn parse(s: &str) -> Result<Self, ParseUuidError> {
-        Uuid::from_str(s)
+    pub fn new_v2(
+        domain: Domain,
+        local_id: u32,
+        timestamp: u64,
+        clock_seq: u16,
+        node: [u8; 6],
+    ) -> Self {
+        let mut uuid = Uuid::nil();
+        uuid.set_time_v1(timestamp);
+        uuid.0[..4].copy_from_slice(&local_id.to_be_bytes());
+        uuid.0[8] = (clock_seq & 0x3F) as u8;
+        uuid.0[9] = domain.into();
+        uuid.0[10..].copy_from_slice(&node);
+        uuid.set_variant(Variant::Rfc4122);
+        uuid.set_version(Version::Dce);
+        uuid
     }
 
     /// Create a new Version 4(Random) UUID.
     ///
     /// This requires the `getrandom` feature.
     ///
-    /// If generating a lot of UUID's very quickly, prefer [`Uuid::new_v4_rng`].
+    /// With the `std` feature this uses [`ThreadRng`], and is fast.
+    /// Otherwise a new [`Rng`] is seeded each time, so if generating a lot of
+    /// UUID's very quickly, prefer [`Uuid::new_v4_rng`].
     ///
     /// # Example
     ///
@@ -449,11 +1941,14 @@ impl Uuid {
     #[cfg(feature = "getrandom")]
     #[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
     pub fn new_v4() -> Self {
-        let mut uuid = Uuid::nil();
-        Rng::new().fill_bytes(&mut uuid.0);
-        uuid.set_variant(Variant::Rfc4122);
-        uuid.set_version(Version::Random);
-        uuid
+        #[cfg(feature = "std")]
+        {
+            Self::new_v4_from_rng(&mut ThreadRng::new())
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Self::new_v4_from_rng(&mut Rng::new())
+        }
     }
 
     /// Create a new Version 4(Random) UUID, using the provided [`Rng`]
@@ -475,7 +1970,39 @@ impl Uuid {
     ///     let uuid = Uuid::new_v4_rng(&mut rng);
     /// }
     /// ```
+    #[inline]
     pub fn new_v4_rng(rng: &mut Rng) -> Self {
+        Self::new_v4_from_rng(rng)
+    }
+
+    /// Create a new Version 4(Random) UUID, using any [`RngCore`] +
+    /// [`CryptoRng`].
+    ///
+    /// See [`Uuid::new_v4_rng`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{CryptoRng, RngCore, RngError, Uuid};
+    /// /// A hardware TRNG.
+    /// struct Trng;
+    ///
+    /// impl RngCore for Trng {
+    ///     // ...
+    /// #   fn next_u32(&mut self) -> u32 { 4 }
+    /// #   fn next_u64(&mut self) -> u64 { 4 }
+    /// #   fn fill_bytes(&mut self, dest: &mut [u8]) { dest.fill(4) }
+    ///     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RngError> {
+    ///         // ...
+    /// #       Ok(self.fill_bytes(dest))
+    ///     }
+    /// }
+    ///
+    /// impl CryptoRng for Trng {}
+    ///
+    /// let uuid = Uuid::new_v4_from_rng(&mut Trng);
+    /// ```
+    pub fn new_v4_from_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
         let mut uuid = Uuid::nil();
         rng.fill_bytes(&mut uuid.0);
         uuid.set_variant(Variant::Rfc4122);
@@ -483,6 +2010,38 @@ impl Uuid {
         uuid
     }
 
+    /// Create a new Version 7(Unix Epoch time) UUID, using the provided
+    /// [`RngCore`] + [`CryptoRng`], such as [`Rng`].
+    ///
+    /// `timestamp` is the number of milliseconds since the Unix Epoch,
+    /// 1970-01-01 00:00:00 UTC, ignoring leap seconds.
+    /// Only the lowest 48 bits are used.
+    ///
+    /// The remaining bits are random.
+    /// Version 7 UUID's sort by their timestamp, making them well suited
+    /// for use as database keys.
+    ///
+    /// Providing the time is left to you,
+    /// so this works in `no_std` environments.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Rng, Uuid};
+    /// # let seed = [0; 32];
+    /// let mut rng = Rng::from_seed(seed);
+    /// let uuid = Uuid::new_v7(0x017F22E279B0, &mut rng);
+    /// assert!(format!("{:x}", uuid).starts_with("017f22e2-79b0-7"));
+    /// ```
+    pub fn new_v7<R: RngCore + CryptoRng>(timestamp: u64, rng: &mut R) -> Self {
+        let mut uuid = Uuid::nil();
+        rng.fill_bytes(&mut uuid.0[6..]);
+        uuid.0[..6].copy_from_slice(&timestamp.to_be_bytes()[2..]);
+        uuid.set_variant(Variant::Rfc4122);
+        uuid.set_version(Version::UnixTime);
+        uuid
+    }
+
     /// Create a new Version 3 UUID with the provided name and namespace.
     ///
     /// # Note
@@ -523,116 +2082,393 @@ impl Uuid {
         uuid.set_variant(Variant::Rfc4122);
         uuid
     }
+
+    /// Create a new Version 8(Vendor) UUID from `bytes`.
+    ///
+    /// The version and variant bits are overwritten,
+    /// leaving the other 122 bits as provided.
+    ///
+    /// See [`CustomBuilder`] for building these from bit fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Uuid, Version};
+    /// let uuid = Uuid::new_v8([0xFF; 16]);
+    /// assert_eq!(uuid.version(), Version::Vendor);
+    /// assert_eq!(format!("{:x}", uuid), "ffffffff-ffff-8fff-bfff-ffffffffffff");
+    /// ```
+    pub fn new_v8(bytes: Bytes) -> Self {
+        let mut uuid = Uuid::from_bytes(bytes);
+        uuid.set_variant(Variant::Rfc4122);
+        uuid.set_version(Version::Vendor);
+        uuid
+    }
+}
+
+/// See [`Uuid::parse`] for details.
+impl FromStr for Uuid {
+    type Err = ParseUuidError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_fast(s.as_bytes())
+            .map(Uuid::from_bytes)
+            .map_err(|e| e.with_str(s))
+    }
+}
+
+/// Parse a UUID in any supported format, as fast as possible.
+///
+/// The hex digits are gathered and decoded all at once,
+/// falling back to [`parse`] for detailed errors.
+fn parse_fast(s: &[u8]) -> Result<Bytes, ParseUuidError> {
+    /// Gather the digits of the hyphenated format, if the hyphens are correct.
+    fn gather(s: &[u8]) -> Option<[u8; 32]> {
+        if s[8] != b'-' || s[13] != b'-' || s[18] != b'-' || s[23] != b'-' {
+            return None;
+        }
+        let mut digits = [0; 32];
+        digits[..8].copy_from_slice(&s[..8]);
+        digits[8..12].copy_from_slice(&s[9..13]);
+        digits[12..16].copy_from_slice(&s[14..18]);
+        digits[16..20].copy_from_slice(&s[19..23]);
+        digits[20..].copy_from_slice(&s[24..36]);
+        Some(digits)
+    }
+
+    let urn = UUID_URN.as_bytes();
+    let digits = match s.len() {
+        UUID_SIMPLE_LENGTH => s.try_into().ok(),
+        UUID_STR_LENGTH => gather(s),
+        UUID_BRACED_LENGTH if s[0] == b'{' && s[UUID_BRACED_LENGTH - 1] == b'}' => gather(&s[1..]),
+        UUID_URN_LENGTH if s[..urn.len()].eq_ignore_ascii_case(urn) => gather(&s[urn.len()..]),
+        _ => None,
+    };
+    match digits.as_ref().and_then(hex::decode) {
+        Some(bytes) => Ok(bytes),
+        None => parse(s),
+    }
+}
+
+/// Parse a UUID in any supported format.
+///
+/// This is a `const fn` so it can be used by [`uuid!`].
+const fn parse(s: &[u8]) -> Result<Bytes, ParseUuidError> {
+    match s.len() {
+        UUID_STR_LENGTH => parse_hyphenated(s, 0),
+        UUID_SIMPLE_LENGTH => parse_simple(s),
+        UUID_BRACED_LENGTH => {
+            // Without an opening brace, this is no format at all.
+            if s[0] != b'{' {
+                return Err(ParseUuidError::new(
+                    ParseErrorKind::InvalidLength,
+                    UUID_BRACED_LENGTH,
+                    None,
+                ));
+            }
+            // Check the body first, so the closing brace index is always
+            // on a character boundary.
+            let raw = match parse_hyphenated(s, 1) {
+                Ok(raw) => raw,
+                Err(e) => return Err(e),
+            };
+            let end = UUID_BRACED_LENGTH - 1;
+            if s[end] != b'}' {
+                return Err(ParseUuidError::at(ParseErrorKind::InvalidCharacter, s, end));
+            }
+            Ok(raw)
+        }
+        UUID_URN_LENGTH => {
+            let urn = UUID_URN.as_bytes();
+            let mut i = 0;
+            while i < urn.len() {
+                if !s[i].eq_ignore_ascii_case(&urn[i]) {
+                    return Err(ParseUuidError::at(ParseErrorKind::InvalidUrn, s, i));
+                }
+                i += 1;
+            }
+            parse_hyphenated(s, urn.len())
+        }
+        len => Err(ParseUuidError::new(
+            ParseErrorKind::InvalidLength,
+            len,
+            None,
+        )),
+    }
+}
+
+/// Parse the 36 character hyphenated format, starting at `start` in `s`.
+const fn parse_hyphenated(s: &[u8], start: usize) -> Result<Bytes, ParseUuidError> {
+    const GROUPS: [usize; 5] = [8, 4, 4, 4, 12];
+    let mut raw = [0; 16];
+    let mut nibble = 0;
+    let mut group = 0;
+    let mut len = 0;
+    let mut i = start;
+    while i < start + UUID_STR_LENGTH {
+        let c = s[i];
+        if c == b'-' {
+            if group == GROUPS.len() - 1 {
+                return Err(ParseUuidError::at(ParseErrorKind::ExtraHyphen, s, i));
+            }
+            if len != GROUPS[group] {
+                return Err(ParseUuidError::at(ParseErrorKind::InvalidGroupLength, s, i));
+            }
+            group += 1;
+            len = 0;
+        } else {
+            let digit = match hex(c) {
+                Some(d) => d,
+                None => return Err(ParseUuidError::at(ParseErrorKind::InvalidCharacter, s, i)),
+            };
+            if len == GROUPS[group] {
+                return Err(ParseUuidError::at(ParseErrorKind::MissingHyphen, s, i));
+            }
+            raw[nibble / 2] |= digit << (4 * (1 - nibble % 2));
+            nibble += 1;
+            len += 1;
+        }
+        i += 1;
+    }
+    // Every group but the last was checked at its hyphen, and no group can
+    // grow past its length, so 36 characters means all 32 digits were read.
+    Ok(raw)
+}
+
+/// Parse the 32 character simple format.
+const fn parse_simple(s: &[u8]) -> Result<Bytes, ParseUuidError> {
+    let mut raw = [0; 16];
+    let mut i = 0;
+    while i < UUID_SIMPLE_LENGTH {
+        let digit = match (s[i], hex(s[i])) {
+            (_, Some(d)) => d,
+            (b'-', None) => return Err(ParseUuidError::at(ParseErrorKind::ExtraHyphen, s, i)),
+            (_, None) => return Err(ParseUuidError::at(ParseErrorKind::InvalidCharacter, s, i)),
+        };
+        raw[i / 2] |= digit << (4 * (1 - i % 2));
+        i += 1;
+    }
+    Ok(raw)
+}
+
+/// Decode a single ASCII hex digit.
+#[inline]
+const fn hex(c: u8) -> Option<u8> {
+    match hex::DECODE[c as usize] {
+        hex::INVALID => None,
+        d => Some(d),
+    }
+}
+
+/// Display the [`Uuid`] in uppercase hex.
+///
+/// With the `lowercase` feature, this is lowercase instead,
+/// as recommended by RFC 4122.
+/// [`Uuid::hyphenated`] is always lowercase.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::Uuid;
+/// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+/// # #[cfg(not(feature = "lowercase"))]
+/// assert_eq!(format!("{}", uuid), "662AA7C7-7598-4D56-8BCC-A72C30F998A2");
+/// # #[cfg(feature = "lowercase")]
+/// # assert_eq!(format!("{}", uuid), "662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+/// ```
+impl fmt::Display for Uuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Always hyphenated, ignoring the alternate flag.
+        if cfg!(feature = "lowercase") {
+            fmt::LowerHex::fmt(&self.hyphenated(), f)
+        } else {
+            fmt::UpperHex::fmt(&self.hyphenated(), f)
+        }
+    }
+}
+
+impl fmt::Debug for Uuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Uuid({})", self)
+    }
+}
+
+/// Display the [`Uuid`] in lowercase
+///
+/// The alternate(`#`) flag can be used to get a URN.
+///
+/// Width, fill, and alignment are supported.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::Uuid;
+/// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+/// assert_eq!(format!("{:x}", uuid), "662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+/// assert_eq!(format!("{:#x}", uuid), "urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+/// assert_eq!(format!("{:*^40x}", uuid), "**662aa7c7-7598-4d56-8bcc-a72c30f998a2**");
+/// ```
+impl fmt::LowerHex for Uuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            fmt::LowerHex::fmt(&self.urn(), f)
+        } else {
+            fmt::LowerHex::fmt(&self.hyphenated(), f)
+        }
+    }
+}
+
+/// Display the [`Uuid`] in uppercase
+///
+/// The alternate(`#`) flag can be used to get a URN.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::Uuid;
+/// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+/// assert_eq!(format!("{:X}", uuid), "662AA7C7-7598-4D56-8BCC-A72C30F998A2");
+/// assert_eq!(format!("{:#X}", uuid), "urn:uuid:662AA7C7-7598-4D56-8BCC-A72C30F998A2");
+/// ```
+impl fmt::UpperHex for Uuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            fmt::UpperHex::fmt(&self.urn(), f)
+        } else {
+            fmt::UpperHex::fmt(&self.hyphenated(), f)
+        }
+    }
+}
+
+/// A [`Uuid`] formatted with hyphens.
+///
+/// Displays in lowercase, and supports [`fmt::LowerHex`] and
+/// [`fmt::UpperHex`], with width, fill, and alignment.
+///
+/// See [`Uuid::hyphenated`].
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Hyphenated(Uuid);
+
+impl fmt::Display for Hyphenated {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for Hyphenated {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; 36];
+        f.pad(self.0.to_str(&mut buf))
+    }
+}
+
+impl fmt::UpperHex for Hyphenated {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; 36];
+        f.pad(self.0.to_str_upper(&mut buf))
+    }
+}
+
+/// A [`Uuid`] formatted as a URN.
+///
+/// Displays in lowercase, and supports [`fmt::LowerHex`] and
+/// [`fmt::UpperHex`], with width, fill, and alignment.
+///
+/// See [`Uuid::urn`].
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Urn(Uuid);
+
+impl fmt::Display for Urn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for Urn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; 45];
+        f.pad(self.0.to_urn(&mut buf))
+    }
+}
+
+impl fmt::UpperHex for Urn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; 45];
+        f.pad(self.0.to_urn_upper(&mut buf))
+    }
+}
+
+/// A [`Uuid`] formatted without hyphens.
+///
+/// Displays in lowercase, and supports [`fmt::LowerHex`] and
+/// [`fmt::UpperHex`], with width, fill, and alignment.
+///
+/// See [`Uuid::simple`].
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Simple(Uuid);
+
+impl fmt::Display for Simple {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
 }
 
-/// See [`Uuid::parse`] for details.
-impl FromStr for Uuid {
-    type Err = ParseUuidError;
+impl fmt::LowerHex for Simple {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; 32];
+        f.pad(self.0.to_str_simple(&mut buf))
+    }
+}
 
-    fn from_str(mut s: &str) -> Result<Self, Self::Err> {
-        if s.len() == UUID_URN_LENGTH {
-            s = &s[UUID_URN.len()..];
-        }
-        if s.len() != UUID_STR_LENGTH {
-            return Err(ParseUuidError);
-        }
-        let mut raw = [0; 16];
-        let mut buf: &mut [u8] = &mut raw;
-        for data in s.split('-') {
-            match data.len() {
-                8 => {
-                    buf[..4].copy_from_slice(
-                        &u32::from_str_radix(data, 16)
-                            .or(Err(ParseUuidError))?
-                            .to_be_bytes(),
-                    );
-                    buf = &mut buf[4..];
-                }
-                4 => {
-                    buf[..2].copy_from_slice(
-                        &u16::from_str_radix(data, 16)
-                            .or(Err(ParseUuidError))?
-                            .to_be_bytes(),
-                    );
-                    buf = &mut buf[2..];
-                }
-                12 => {
-                    buf[..6].copy_from_slice(
-                        &u64::from_str_radix(data, 16)
-                            .or(Err(ParseUuidError))?
-                            .to_be_bytes()[2..],
-                    );
-                }
-                _ => return Err(ParseUuidError),
-            }
-        }
-        Ok(Uuid::from_bytes(raw))
+impl fmt::UpperHex for Simple {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; 32];
+        f.pad(self.0.to_str_simple_upper(&mut buf))
     }
 }
 
-/// Display the [`Uuid`] in uppercase hex.
+/// A [`Uuid`] formatted surrounded by braces.
 ///
-/// # Example
+/// Displays in lowercase, and supports [`fmt::LowerHex`] and
+/// [`fmt::UpperHex`], with width, fill, and alignment.
 ///
-/// ```rust
-/// # use nuuid::Uuid;
-/// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
-/// assert_eq!(format!("{}", uuid), "662AA7C7-7598-4D56-8BCC-A72C30F998A2");
-/// ```
-impl fmt::Display for Uuid {
+/// See [`Uuid::braced`].
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Braced(Uuid);
+
+impl fmt::Display for Braced {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:X}", self)
+        fmt::LowerHex::fmt(self, f)
     }
 }
 
-impl fmt::Debug for Uuid {
+impl fmt::LowerHex for Braced {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Uuid({:X})", self)
+        let mut buf = [0; 38];
+        f.pad(self.0.to_str_braced(&mut buf))
     }
 }
 
-/// Display the [`Uuid`] in lowercase
-///
-/// The alternate(`#`) flag can be used to get a URN.
-///
-/// # Example
-///
-/// ```rust
-/// # use nuuid::Uuid;
-/// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
-/// assert_eq!(format!("{:x}", uuid), "662aa7c7-7598-4d56-8bcc-a72c30f998a2");
-/// assert_eq!(format!("{:#x}", uuid), "urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2");
-/// ```
-impl fmt::LowerHex for Uuid {
+impl fmt::UpperHex for Braced {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if f.alternate() {
-            write!(f, "{}", UUID_URN)?;
-        }
-        let mut buf = [0; 36];
-        let s = self.to_str(&mut buf);
-        write!(f, "{}", s)
+        let mut buf = [0; 38];
+        f.pad(self.0.to_str_braced_upper(&mut buf))
     }
 }
 
-/// Display the [`Uuid`] in uppercase
-///
-/// The alternate(`#`) flag can be used to get a URN.
-///
-/// # Example
-///
-/// ```rust
-/// # use nuuid::Uuid;
-/// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
-/// assert_eq!(format!("{:X}", uuid), "662AA7C7-7598-4D56-8BCC-A72C30F998A2");
-/// assert_eq!(format!("{:#X}", uuid), "urn:uuid:662AA7C7-7598-4D56-8BCC-A72C30F998A2");
-/// ```
-impl fmt::UpperHex for Uuid {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if f.alternate() {
-            write!(f, "{}", UUID_URN)?;
-        }
-        let mut buf = [0; 36];
-        write!(f, "{}", self.to_str_upper(&mut buf))
+/// See [`Uuid::from_u128`] for details.
+impl From<u128> for Uuid {
+    #[inline]
+    fn from(v: u128) -> Self {
+        Uuid::from_u128(v)
+    }
+}
+
+/// See [`Uuid::to_u128`] for details.
+impl From<Uuid> for u128 {
+    #[inline]
+    fn from(uuid: Uuid) -> Self {
+        uuid.to_u128()
     }
 }
 
@@ -655,13 +2491,25 @@ mod tests {
     use super::*;
 
     const UUID_NIL: &str = "00000000-0000-0000-0000-000000000000";
+    const UUID_MAX: &str = "FFFFFFFF-FFFF-FFFF-FFFF-FFFFFFFFFFFF";
     const UUID_V4: &str = "662aa7c7-7598-4d56-8bcc-a72c30f998a2";
     const UUID_V4_URN: &str = "urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2";
     const UUID_V4_URN_UPPER: &str = "urn:uuid:662AA7C7-7598-4D56-8BCC-A72C30F998A2";
+    const UUID_V4_SIMPLE: &str = "662aa7c775984d568bcca72c30f998a2";
+    const UUID_V4_BRACED: &str = "{662aa7c7-7598-4d56-8bcc-a72c30f998a2}";
     const RAW: [u8; 16] = [
         102, 42, 167, 199, 117, 152, 77, 86, 139, 204, 167, 44, 48, 249, 152, 162,
     ];
 
+    /// Expected `Display` output for [`UUID_V4`].
+    fn display() -> String {
+        if cfg!(feature = "lowercase") {
+            UUID_V4.into()
+        } else {
+            UUID_V4.to_ascii_uppercase()
+        }
+    }
+
     fn name(fun: fn(Uuid, &[u8]) -> Uuid, ver: Version) {
         let namespace = Uuid::new_v4();
         let namespace2 = Uuid::new_v4();
@@ -692,6 +2540,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::redundant_closure)]
     fn md5() {
         name(
             |namespace, name| Uuid::new_v3(namespace, name),
@@ -707,6 +2556,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::redundant_closure)]
     fn sha1() {
         name(
             |namespace, name| Uuid::new_v5(namespace, name),
@@ -714,12 +2564,317 @@ mod tests {
         )
     }
 
+    #[test]
+    fn time() {
+        let node = [0x9F, 0x6B, 0xDE, 0xCE, 0xD8, 0x46];
+        let uuid = Uuid::new_v1(0x1EC9414C232AB00, 0x33C8, node);
+        assert_eq!(
+            uuid,
+            // From RFC 9562 Appendix A.1
+            Uuid::from_str("C232AB00-9414-11EC-B3C8-9F6BDECED846").unwrap()
+        );
+        assert_eq!(uuid.version(), Version::Time);
+        assert_eq!(uuid.variant(), Variant::Rfc4122);
+
+        let mut ctx = ClockSequence::new(0x3FFF);
+        assert_eq!(ctx.next(10), 0x3FFF);
+        assert_eq!(ctx.next(11), 0x3FFF, "Later timestamps must not change");
+        assert_eq!(ctx.next(11), 0, "Same timestamp must increment and wrap");
+        assert_eq!(ctx.next(5), 1, "Clock regression must increment");
+        assert_eq!(ctx.next(6), 1);
+    }
+
+    #[test]
+    fn timestamp() {
+        let node = [0x9F, 0x6B, 0xDE, 0xCE, 0xD8, 0x46];
+        let ts = Timestamp::Gregorian(0x1EC9414C232AB00);
+        let v1 = Uuid::new_v1(0x1EC9414C232AB00, 0x33C8, node);
+        let v6 = Uuid::new_v6(0x1EC9414C232AB00, 0x33C8, node);
+        assert_eq!(v1.timestamp(), Some(ts));
+        assert_eq!(v6.timestamp(), Some(ts));
+        // Tuesday, February 22, 2022 2:22:22.000000 PM GMT-05:00
+        assert_eq!(ts.to_unix(), (1_645_557_742, 0));
+
+        let v7 = Uuid::new_v7(0x017F22E279B0, &mut Rng::from_seed([0; 32]));
+        assert_eq!(v7.timestamp(), Some(Timestamp::Unix(0x017F22E279B0)));
+        assert_eq!(
+            Timestamp::Unix(0x017F22E279B0 + 1).to_unix(),
+            (1_645_557_742, 1_000_000)
+        );
+
+        assert_eq!(Timestamp::Gregorian(1).to_unix(), (-12_219_292_800, 100));
+        assert_eq!(Uuid::new_v3(NAMESPACE_DNS, b"test").timestamp(), None);
+
+        #[cfg(feature = "std")]
+        {
+            use std::time::{Duration, UNIX_EPOCH};
+            let time = UNIX_EPOCH + Duration::new(1_645_557_742, 0);
+            assert_eq!(ts.to_system_time(), Some(time));
+            // Not every platform can go back to 1582.
+            let before = UNIX_EPOCH
+                .checked_sub(Duration::new(12_219_292_800, 0))
+                .and_then(|t| t.checked_add(Duration::from_nanos(100)));
+            assert_eq!(Timestamp::Gregorian(1).to_system_time(), before);
+        }
+    }
+
+    #[test]
+    fn dce() {
+        let node = [0x9F, 0x6B, 0xDE, 0xCE, 0xD8, 0x46];
+        let uuid = Uuid::new_v2(Domain::Group, 0xDEADBEEF, 0x1EC9414C232AB00, 0xFFFF, node);
+        assert_eq!(
+            uuid,
+            Uuid::from_str("DEADBEEF-9414-21EC-BF01-9F6BDECED846").unwrap()
+        );
+        assert_eq!(uuid.version(), Version::Dce);
+        assert_eq!(uuid.variant(), Variant::Rfc4122);
+        assert_eq!(uuid.local_domain(), Some(Domain::Group));
+        assert_eq!(uuid.local_id(), Some(0xDEADBEEF));
+
+        let uuid = Uuid::new_v2(Domain::Other(9), 0, 0, 0, node);
+        assert_eq!(uuid.local_domain(), Some(Domain::Other(9)));
+
+        let uuid = Uuid::new_v1(0x1EC9414C232AB00, 0x33C8, node);
+        assert_eq!(uuid.local_domain(), None);
+        assert_eq!(uuid.local_id(), None);
+    }
+
+    #[test]
+    fn reordered_time() {
+        let node = [0x9F, 0x6B, 0xDE, 0xCE, 0xD8, 0x46];
+        let uuid = Uuid::new_v6(0x1EC9414C232AB00, 0x33C8, node);
+        assert_eq!(
+            uuid,
+            // From RFC 9562 Appendix A.5
+            Uuid::from_str("1EC9414C-232A-6B00-B3C8-9F6BDECED846").unwrap()
+        );
+        assert_eq!(uuid.version(), Version::ReorderedTime);
+        assert_eq!(uuid.variant(), Variant::Rfc4122);
+
+        let v1 = Uuid::new_v1(0x1EC9414C232AB00, 0x33C8, node);
+        assert_eq!(v1.v1_to_v6(), Some(uuid));
+        assert_eq!(uuid.v6_to_v1(), Some(v1));
+        assert_eq!(uuid.v1_to_v6(), None);
+        assert_eq!(v1.v6_to_v1(), None);
+
+        // Non-RFC variant and clock sequence bits must survive the round trip
+        let mut legacy = v1;
+        legacy.set_variant(Variant::Microsoft);
+        let round = legacy.v1_to_v6().and_then(Uuid::v6_to_v1);
+        assert_eq!(round, Some(legacy));
+    }
+
+    #[test]
+    fn unix_time() {
+        let mut rng = Rng::from_seed([0; 32]);
+        let uuid = Uuid::new_v7(0x017F22E279B0, &mut rng);
+        assert_eq!(uuid.to_bytes()[..6], [0x01, 0x7F, 0x22, 0xE2, 0x79, 0xB0]);
+        assert_eq!(uuid.version(), Version::UnixTime);
+        assert_eq!(uuid.variant(), Variant::Rfc4122);
+
+        // From RFC 9562 Appendix A.6
+        let uuid = Uuid::from_str("017F22E2-79B0-7CC3-98C4-DC0C0C07398F").unwrap();
+        assert_eq!(uuid.version(), Version::UnixTime);
+
+        let later = Uuid::new_v7(0x017F22E279B1, &mut rng);
+        assert!(later > uuid, "Version 7 UUID's must sort by time");
+    }
+
+    #[test]
+    fn unix_time_monotonic() {
+        for &method in &[Monotonic::Counter, Monotonic::Random] {
+            let mut gen = UnixTimeGenerator::new(Rng::from_seed([0; 32]), method);
+            let mut last = gen.next(1000);
+            for ts in [1000, 1000, 999, 500, 1000, 1001, 1001].iter() {
+                let uuid = gen.next(*ts);
+                assert!(uuid > last, "{:?} UUID's must strictly increase", method);
+                assert_eq!(uuid.version(), Version::UnixTime);
+                assert_eq!(uuid.variant(), Variant::Rfc4122);
+                last = uuid;
+            }
+            assert_eq!(last.to_bytes()[..6], 1001u64.to_be_bytes()[2..]);
+
+            // Overflow advances the timestamp
+            gen.counter = match method {
+                Monotonic::Counter => (1 << 42) - 1,
+                Monotonic::Random => (1 << 74) - 1,
+            };
+            let uuid = gen.next(1001);
+            assert!(uuid > last, "{:?} overflow must still increase", method);
+            assert_eq!(uuid.to_bytes()[..6], 1002u64.to_be_bytes()[2..]);
+
+            // Overflow at the maximum timestamp saturates
+            gen.next(UNIX_TIMESTAMP_MASK);
+            gen.counter = match method {
+                Monotonic::Counter => (1 << 42) - 1,
+                Monotonic::Random => (1 << 74) - 1,
+            };
+            let uuid = gen.next(UNIX_TIMESTAMP_MASK);
+            assert_eq!(uuid.to_bytes()[..6], [0xFF; 6]);
+        }
+    }
+
+    #[test]
+    fn vendor() {
+        const A: CustomField = CustomField::new(CustomRegion::A, 0, 48);
+        const B: CustomField = CustomField::new(CustomRegion::B, 0, 12);
+        const C: CustomField = CustomField::new(CustomRegion::C, 0, 62);
+        const C_HIGH: CustomField = CustomField::new(CustomRegion::C, 58, 4);
+
+        let uuid = CustomBuilder::new()
+            .field(A, 0x2489E9AD2EE2)
+            .field(B, 0xE00)
+            .field(C, 0x0EC932D5F69181C0)
+            .build();
+        assert_eq!(
+            uuid,
+            // From RFC 9562 Appendix B.1
+            Uuid::from_str("2489E9AD-2EE2-8E00-8EC9-32D5F69181C0").unwrap()
+        );
+        assert_eq!(uuid.version(), Version::Vendor);
+        assert_eq!(uuid.variant(), Variant::Rfc4122);
+        assert_eq!(uuid.custom(A), 0x2489E9AD2EE2);
+        assert_eq!(uuid.custom(B), 0xE00);
+        assert_eq!(uuid.custom(C), 0x0EC932D5F69181C0);
+        assert_eq!(uuid.custom(C_HIGH), 0b0011);
+
+        let uuid = CustomBuilder::new().field(C_HIGH, u64::MAX).build();
+        assert_eq!(uuid.custom(C_HIGH), 0xF);
+        assert_eq!(uuid.custom(C), 0xF << 58, "Fields must not overflow");
+        assert_eq!(uuid.variant(), Variant::Rfc4122);
+    }
+
     #[test]
     fn parse_string() {
         let uuid = Uuid::from_str(UUID_V4).unwrap();
         assert_eq!(RAW, uuid.to_bytes(), "Parsed UUID bytes don't match");
         let uuid = Uuid::from_str(UUID_V4_URN).unwrap();
         assert_eq!(RAW, uuid.to_bytes(), "Parsed UUID bytes don't match");
+        let uuid = Uuid::from_str(UUID_V4_SIMPLE).unwrap();
+        assert_eq!(RAW, uuid.to_bytes(), "Parsed UUID bytes don't match");
+        let uuid = Uuid::from_str(&UUID_V4_SIMPLE.to_ascii_uppercase()).unwrap();
+        assert_eq!(RAW, uuid.to_bytes(), "Parsed UUID bytes don't match");
+        let uuid = Uuid::from_str(UUID_V4_BRACED).unwrap();
+        assert_eq!(RAW, uuid.to_bytes(), "Parsed UUID bytes don't match");
+    }
+
+    #[test]
+    fn parse_error() {
+        let err = |s: &str| {
+            let e = Uuid::from_str(s).unwrap_err();
+            (e.kind(), e.index(), e.found())
+        };
+        assert_eq!(err(""), (ParseErrorKind::InvalidLength, 0, None));
+        assert_eq!(
+            err(&UUID_V4[1..]),
+            (ParseErrorKind::InvalidLength, 35, None)
+        );
+        assert_eq!(
+            err("662aa7c7-7598-4d56-8bcc-a72c30f998ag"),
+            (ParseErrorKind::InvalidCharacter, 35, Some('g'))
+        );
+        assert_eq!(
+            err("662aa7c7-+598-4d56-8bcc-a72c30f998a2"),
+            (ParseErrorKind::InvalidCharacter, 9, Some('+'))
+        );
+        assert_eq!(
+            err("662aa7c7-7598-4d56-8bcc-a72c30f998é"),
+            (ParseErrorKind::InvalidCharacter, 34, Some('é'))
+        );
+        assert_eq!(
+            err("662aa7c-77598-4d56-8bcc-a72c30f998a2"),
+            (ParseErrorKind::InvalidGroupLength, 7, Some('-'))
+        );
+        assert_eq!(
+            err("662aa7c77-598-4d56-8bcc-a72c30f998a2"),
+            (ParseErrorKind::MissingHyphen, 8, Some('7'))
+        );
+        assert_eq!(
+            err("662aa7c7-7598-4d56-8bcc-a72c30f9-8a2"),
+            (ParseErrorKind::ExtraHyphen, 32, Some('-'))
+        );
+        assert_eq!(
+            err("662aa7c775984d56-bcca72c30f998a2"),
+            (ParseErrorKind::ExtraHyphen, 16, Some('-'))
+        );
+        assert_eq!(
+            err("662aa7c775984d568bcca72c30f998a+"),
+            (ParseErrorKind::InvalidCharacter, 31, Some('+'))
+        );
+        assert_eq!(
+            err("(662aa7c7-7598-4d56-8bcc-a72c30f998a2}"),
+            (ParseErrorKind::InvalidLength, 38, None)
+        );
+        assert_eq!(
+            err("662aa7c7-7598-4d56-8bcc-a72c30f998a2xx"),
+            (ParseErrorKind::InvalidLength, 38, None)
+        );
+        assert_eq!(
+            err("{662aa7c7-7598-4d56-8bcc-a72c30f998aé"),
+            (ParseErrorKind::InvalidCharacter, 36, Some('é'))
+        );
+        assert_eq!(
+            err("{662aa7c7-7598-4d56-8bcc-a72c30f998a2)"),
+            (ParseErrorKind::InvalidCharacter, 37, Some(')'))
+        );
+        assert_eq!(
+            err("{662aa7c7-7598-4d56-8bcc-a72c30f998ag}"),
+            (ParseErrorKind::InvalidCharacter, 36, Some('g'))
+        );
+        assert_eq!(
+            err("urn:uid:-662aa7c7-7598-4d56-8bcc-a72c30f998a2"),
+            (ParseErrorKind::InvalidUrn, 5, Some('i'))
+        );
+        assert_eq!(
+            err("urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998ag"),
+            (ParseErrorKind::InvalidCharacter, 44, Some('g'))
+        );
+        assert_eq!(
+            Uuid::from_str("URN:UUID:662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap(),
+            Uuid::from_bytes(RAW)
+        );
+        assert_eq!(
+            Uuid::from_str("662aa7c7-7598-4d56-8bcc-a72c30f998ag")
+                .unwrap_err()
+                .to_string(),
+            "invalid character 'g' at index 35"
+        );
+    }
+
+    #[test]
+    fn parse_ascii() {
+        let uuid = Uuid::from_bytes(RAW);
+        for s in &[UUID_V4, UUID_V4_URN, UUID_V4_SIMPLE, UUID_V4_BRACED] {
+            assert_eq!(Uuid::parse_ascii(s.as_bytes()).unwrap(), uuid);
+
+            let mut input = s.as_bytes().to_vec();
+            input.extend_from_slice(b"-trailing-data");
+            assert_eq!(Uuid::try_parse_ascii(&input).unwrap(), (uuid, s.len()));
+            assert_eq!(
+                Uuid::parse_ascii(&input).unwrap_err().kind(),
+                ParseErrorKind::InvalidLength
+            );
+        }
+        let err = Uuid::try_parse_ascii(&UUID_V4.as_bytes()[..30]).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidLength);
+        assert_eq!(err.index(), 30);
+        let err = Uuid::parse_ascii(b"662aa7c7-7598-4d56-8bcc-a72c30f998a\xFF").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidCharacter);
+    }
+
+    #[test]
+    fn literal() {
+        const UUID: Uuid = uuid!("662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+        assert_eq!(UUID, Uuid::from_bytes(RAW));
+        assert_eq!(uuid!(UUID_V4_URN), UUID);
+        assert_eq!(uuid!(UUID_V4_SIMPLE), UUID);
+        assert_eq!(uuid!(UUID_V4_BRACED), UUID);
+        assert_eq!(
+            Uuid::parse_const("662aa7c7-7598-4d56-8bcc-a72c30f998ag")
+                .unwrap_err()
+                .kind(),
+            ParseErrorKind::InvalidCharacter
+        );
     }
 
     #[test]
@@ -747,16 +2902,79 @@ mod tests {
             "UUID URN Display didn't match"
         );
         assert_eq!(format!("{:x}", uuid), UUID_V4, "UUID Display didn't match");
+        assert_eq!(format!("{}", uuid), display(), "UUID Display didn't match");
         assert_eq!(
-            format!("{}", uuid),
-            UUID_V4.to_ascii_uppercase(),
-            "UUID Display didn't match"
+            format!("{:?}", uuid),
+            format!("Uuid({})", display()),
+            "UUID Debug didn't match"
         );
         assert_eq!(
             format!("{}", Uuid::nil()),
             UUID_NIL,
             "Nil UUID Display didn't work!"
         );
+        let mut buf = [0; 32];
+        assert_eq!(
+            uuid.to_str_simple(&mut buf),
+            UUID_V4_SIMPLE,
+            "UUID simple strings didn't match"
+        );
+        assert_eq!(
+            uuid.to_str_simple_upper(&mut buf),
+            &UUID_V4_SIMPLE.to_ascii_uppercase()[..],
+            "UUID simple upper strings didn't match"
+        );
+        assert_eq!(
+            format!("{}", uuid.simple()),
+            UUID_V4_SIMPLE,
+            "UUID simple Display didn't match"
+        );
+        let mut buf = [0; 38];
+        assert_eq!(
+            uuid.to_str_braced(&mut buf),
+            UUID_V4_BRACED,
+            "UUID braced strings didn't match"
+        );
+        assert_eq!(
+            uuid.to_str_braced_upper(&mut buf),
+            &UUID_V4_BRACED.to_ascii_uppercase()[..],
+            "UUID braced upper strings didn't match"
+        );
+        assert_eq!(
+            format!("{}", uuid.braced()),
+            UUID_V4_BRACED,
+            "UUID braced Display didn't match"
+        );
+    }
+
+    #[test]
+    fn padding() {
+        let uuid = Uuid::from_bytes(RAW);
+        let upper = UUID_V4.to_ascii_uppercase();
+        assert_eq!(format!("{:>40}", uuid), format!("    {}", display()));
+        assert_eq!(format!("{:#}", uuid), display(), "Display ignores `#`");
+        assert_eq!(format!("{:<40x}", uuid), format!("{}    ", UUID_V4));
+        assert_eq!(format!("{:-^40X}", uuid), format!("--{}--", upper));
+        assert_eq!(
+            format!("{:>50x}", uuid.urn()),
+            format!("{:>50}", UUID_V4_URN)
+        );
+        assert_eq!(format!("{:<#50x}", uuid), format!("{:<50}", UUID_V4_URN));
+        assert_eq!(
+            format!("{:>35}", uuid.simple()),
+            format!("   {}", UUID_V4_SIMPLE)
+        );
+        assert_eq!(
+            format!("{:.<40}", uuid.braced()),
+            format!("{}..", UUID_V4_BRACED)
+        );
+        assert_eq!(
+            format!("{:>10}", uuid.hyphenated()),
+            UUID_V4,
+            "Width below length"
+        );
+        assert_eq!(format!("{}", uuid.hyphenated()), UUID_V4);
+        assert_eq!(format!("{:X}", uuid.urn()), UUID_V4_URN_UPPER);
     }
 
     #[test]
@@ -770,11 +2988,252 @@ mod tests {
         assert_eq!(uuid_le.variant(), Variant::Rfc4122);
     }
 
+    #[test]
+    fn fields() {
+        let uuid = Uuid::from_bytes(RAW);
+        let (d1, d2, d3, d4) = uuid.as_fields();
+        assert_eq!((d1, d2, d3), (0x662AA7C7, 0x7598, 0x4D56));
+        assert_eq!(d4, &RAW[8..]);
+        assert_eq!(Uuid::from_fields(d1, d2, d3, d4), uuid);
+
+        let (d1, d2, d3, d4) = uuid.to_fields_le();
+        assert_eq!((d1, d2, d3), (0xC7A72A66, 0x9875, 0x564D));
+        assert_eq!(Uuid::from_fields_le(d1, d2, d3, &d4), uuid);
+        assert_eq!(
+            Uuid::from_fields(d1, d2, d3, &d4).to_bytes(),
+            uuid.to_bytes_me(),
+            "Mixed-endian fields must match mixed-endian bytes"
+        );
+    }
+
+    #[test]
+    fn integer() {
+        let uuid = Uuid::from_bytes(RAW);
+        let v = 0x662AA7C7_7598_4D56_8BCC_A72C30F998A2;
+        assert_eq!(uuid.to_u128(), v);
+        assert_eq!(Uuid::from_u128(v), uuid);
+        assert_eq!(u128::from(uuid), v);
+        assert_eq!(Uuid::from(v), uuid);
+
+        let le = uuid.to_u128_le();
+        assert_eq!(le.to_le_bytes(), uuid.to_bytes_me());
+        assert_eq!(Uuid::from_u128_le(le), uuid);
+
+        assert_eq!(uuid.successor().unwrap().to_u128(), v + 1);
+        assert_eq!(uuid.predecessor().unwrap().to_u128(), v - 1);
+        assert_eq!(Uuid::nil().predecessor(), None);
+        assert_eq!(Uuid::from_u128(u128::MAX).successor(), None);
+        let carry = Uuid::from_u128(u64::MAX.into()).successor().unwrap();
+        assert_eq!(carry.to_bytes()[7..9], [1, 0], "Must carry across bytes");
+    }
+
+    #[test]
+    fn edit() {
+        let uuid = Uuid::from_bytes(RAW);
+        for (n, &ver) in (1..).zip(&[
+            Version::Time,
+            Version::Dce,
+            Version::Md5,
+            Version::Random,
+            Version::Sha1,
+            Version::ReorderedTime,
+            Version::UnixTime,
+            Version::Vendor,
+        ]) {
+            assert_eq!(ver.number(), Some(n));
+            let edited = uuid.with_version(ver).unwrap();
+            assert_eq!(edited.version(), ver);
+            let (mut a, mut b) = (edited.to_bytes(), uuid.to_bytes());
+            assert_eq!(a[6] >> 4, n);
+            a[6] &= 0x0F;
+            b[6] &= 0x0F;
+            assert_eq!(a, b, "{:?} must only touch the version bits", ver);
+        }
+        assert_eq!(
+            uuid.with_version(Version::Nil),
+            Err(VersionError(Version::Nil))
+        );
+        assert_eq!(
+            uuid.with_version(Version::Invalid).unwrap_err().version(),
+            Version::Invalid
+        );
+
+        let ones = Uuid::from_bytes([0xFF; 16]);
+        let zeros = Uuid::nil();
+        for &(var, mask, bits) in &[
+            (Variant::Ncs, 0x80, 0x00),
+            (Variant::Rfc4122, 0xC0, 0x80),
+            (Variant::Microsoft, 0xE0, 0xC0),
+            (Variant::Reserved, 0xE0, 0xE0),
+        ] {
+            for &uuid in &[ones, zeros] {
+                let edited = uuid.with_variant(var);
+                assert_eq!(edited.variant(), var);
+                assert_eq!(edited.to_bytes()[8] & mask, bits);
+                assert_eq!(
+                    edited.to_bytes()[8] & !mask,
+                    uuid.to_bytes()[8] & !mask,
+                    "{:?} must only touch its own bits",
+                    var
+                );
+                let mut rest = edited.to_bytes();
+                rest[8] = uuid.to_bytes()[8];
+                assert_eq!(rest, uuid.to_bytes());
+            }
+        }
+    }
+
+    /// Fills with an incrementing byte stream.
+    struct StreamRng(u8);
+
+    impl RngCore for StreamRng {
+        fn next_u32(&mut self) -> u32 {
+            let mut bytes = [0; 4];
+            self.fill_bytes(&mut bytes);
+            u32::from_le_bytes(bytes)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            let mut bytes = [0; 8];
+            self.fill_bytes(&mut bytes);
+            u64::from_le_bytes(bytes)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for b in dest {
+                *b = self.0;
+                self.0 = self.0.wrapping_add(1);
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RngError> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for StreamRng {}
+
+    #[test]
+    fn custom_rng() {
+        let uuid = Uuid::new_v4_from_rng(&mut StreamRng(0));
+        assert_eq!(
+            uuid.to_str(&mut [0; 36]),
+            "00010203-0405-4607-8809-0a0b0c0d0e0f"
+        );
+        let uuid = Uuid::new_v7(0x017F22E279B0, &mut StreamRng(0));
+        assert_eq!(
+            uuid.to_str(&mut [0; 36]),
+            "017f22e2-79b0-7001-8203-040506070809"
+        );
+        let mut seq = ClockSequence::from_rng(&mut StreamRng(0x42));
+        assert_eq!(seq.next(0), 0x0243);
+
+        let mut gen = UnixTimeGenerator::new(StreamRng(0), Monotonic::Random);
+        let first = gen.next(0x017F22E279B0);
+        assert!(gen.next(0x017F22E279B0) > first);
+        let mut rng = gen.into_rng();
+        assert_ne!(rng.next_u32(), 0);
+
+        // `Rng` works as a generic RNG, and can be seeded from any other.
+        let mut rng = Rng::from_rng(StreamRng(0));
+        assert_eq!(
+            Uuid::new_v4_rng(&mut rng.clone()),
+            Uuid::new_v4_from_rng(&mut rng)
+        );
+        let mut gen = UnixTimeGenerator::new(&mut rng, Monotonic::Counter);
+        gen.next(0);
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "getrandom"))]
+    fn reseeding_rng() {
+        /// Whether `rng` reseeded itself while generating 16 bytes.
+        fn reseeds(rng: &mut ReseedingRng) -> bool {
+            let mut old = rng.rng.clone();
+            let (mut expected, mut actual) = ([0; 16], [0; 16]);
+            old.fill_bytes(&mut expected);
+            rng.fill_bytes(&mut actual);
+            expected != actual
+        }
+
+        let mut rng = ReseedingRng::new(32);
+        assert!(!reseeds(&mut rng));
+        assert!(!reseeds(&mut rng));
+        // Threshold reached.
+        assert!(reseeds(&mut rng));
+        assert_eq!(rng.used, 16);
+
+        // Explicitly.
+        let mut old = rng.rng.clone();
+        rng.reseed();
+        assert_eq!(rng.used, 0);
+        assert_ne!(old.next_u64(), rng.next_u64());
+
+        // Forked.
+        assert!(!reseeds(&mut rng));
+        rng.forks = rng.forks.wrapping_add(1);
+        assert!(reseeds(&mut rng));
+        assert_eq!(rng.forks, fork::forks());
+        assert!(!reseeds(&mut rng));
+
+        // Clones of `Rng` are identical.
+        let mut rng = Rng::new();
+        assert_eq!(
+            Uuid::new_v4_rng(&mut rng.clone()),
+            Uuid::new_v4_rng(&mut rng)
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "getrandom"))]
+    fn thread_rng() {
+        let mut rng = ThreadRng::new();
+        let first = Uuid::new_v4_from_rng(&mut rng);
+        // Cross the reseed threshold a few times.
+        let mut buf = vec![0; ReseedingRng::DEFAULT_THRESHOLD / 3 + 1];
+        for _ in 0..10 {
+            rng.fill_bytes(&mut buf);
+            assert!(buf.iter().any(|&b| b != 0));
+            THREAD_RNG
+                .with(|r| assert!(r.borrow().used <= ReseedingRng::DEFAULT_THRESHOLD + buf.len()));
+        }
+        assert_ne!(Uuid::new_v4(), first);
+        assert_ne!(Uuid::new_v4(), Uuid::new_v4());
+
+        let other = std::thread::spawn(Uuid::new_v4).join().unwrap();
+        assert_ne!(other, Uuid::new_v4());
+        assert_eq!(other.version(), Version::Random);
+    }
+
     #[test]
     fn info() {
         let uuid = Uuid::from_bytes(RAW);
         assert_eq!(uuid.version(), Version::Random);
         assert_eq!(uuid.variant(), Variant::Rfc4122);
+
+        assert!(Uuid::nil().is_nil());
+        assert!(!Uuid::nil().is_max());
+        assert_eq!(Uuid::nil().version(), Version::Nil);
+        assert!(Uuid::max().is_max());
+        assert!(!Uuid::max().is_nil());
+        assert_eq!(Uuid::max().version(), Version::Max);
+        assert_eq!(Uuid::max().variant(), Variant::Reserved);
+        assert_eq!(Uuid::max().to_str_upper(&mut [0; 36]), UUID_MAX);
+
+        // Only the exact nil and max UUID's are special.
+        let mut bytes = [0; 16];
+        bytes[15] = 1;
+        assert!(!Uuid::from_bytes(bytes).is_nil());
+        assert_eq!(Uuid::from_bytes(bytes).version(), Version::Invalid);
+        let mut bytes = [0xFF; 16];
+        bytes[0] = 0xFE;
+        assert!(!Uuid::from_bytes(bytes).is_max());
+        assert_eq!(Uuid::from_bytes(bytes).version(), Version::Invalid);
+        let uuid = uuid.with_variant(Variant::Rfc4122);
+        let mut bytes = uuid.to_bytes();
+        bytes[6] &= 0x0F;
+        assert_eq!(Uuid::from_bytes(bytes).version(), Version::Invalid);
         #[cfg(feature = "getrandom")]
         {
             let uuid = Uuid::new_v4();
@@ -0,0 +1,500 @@
+//! Serde support for [`Uuid`].
+//!
+//! By default, human readable formats use the lowercase hyphenated string,
+//! and other formats use the 16 raw bytes.
+//!
+//! The modules here can be used with `#[serde(with = "...")]` to pick a
+//! different representation per field.
+//! Each has an `option` module for [`Option<Uuid>`](Uuid) fields.
+//!
+//! The string representations deserialize from any format
+//! [`Uuid::parse`] supports, not just their own.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use nuuid::Uuid;
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "nuuid::serde::urn")]
+//!     id: Uuid,
+//!     #[serde(with = "nuuid::serde::simple::option")]
+//!     parent: Option<Uuid>,
+//! }
+//!
+//! let record = Record {
+//!     id: Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap(),
+//!     parent: None,
+//! };
+//! assert_eq!(
+//!     serde_json::to_string(&record).unwrap(),
+//!     r#"{"id":"urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2","parent":null}"#
+//! );
+//! ```
+use crate::{ParseErrorKind, Uuid};
+use ::serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use core::{convert::TryInto, fmt};
+
+impl Serialize for Uuid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.to_str(&mut [0; 36]))
+        } else {
+            serializer.serialize_bytes(&self.to_bytes())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Uuid {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(UuidVisitor)
+        } else {
+            deserializer.deserialize_bytes(UuidVisitor)
+        }
+    }
+}
+
+/// Deserialize a [`Uuid`] from a string.
+fn deserialize_str<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uuid, D::Error> {
+    deserializer.deserialize_str(UuidVisitor)
+}
+
+/// Read exactly 16 bytes from `seq`.
+fn seq_bytes<'de, A: SeqAccess<'de>>(
+    mut seq: A,
+    exp: &dyn de::Expected,
+) -> Result<[u8; 16], A::Error> {
+    let mut bytes = [0; 16];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(i, exp))?;
+    }
+    if seq.next_element::<de::IgnoredAny>()?.is_some() {
+        return Err(de::Error::invalid_length(17, exp));
+    }
+    Ok(bytes)
+}
+
+/// Accepts a UUID string in any format [`Uuid::parse`] supports,
+/// 16 bytes, a sequence of 16 bytes, or a [`u128`].
+struct UuidVisitor;
+
+impl<'de> Visitor<'de> for UuidVisitor {
+    type Value = Uuid;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a UUID string, 16 bytes, or a u128")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Uuid::parse(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        match v.try_into() {
+            Ok(bytes) => Ok(Uuid::from_bytes(bytes)),
+            // Some formats represent strings as bytes.
+            Err(_) => Uuid::parse_ascii(v).map_err(|e| match e.kind() {
+                ParseErrorKind::InvalidLength => E::invalid_length(v.len(), &self),
+                _ => E::custom(e),
+            }),
+        }
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        seq_bytes(seq, &self).map(Uuid::from_bytes)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.visit_u128(v.into())
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        Ok(Uuid::from_u128(v))
+    }
+}
+
+/// Accepts exactly 16 bytes, or a sequence of 16 bytes.
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = [u8; 16];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("16 bytes")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        v.try_into().map_err(|_| E::invalid_length(v.len(), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        seq_bytes(seq, &self)
+    }
+}
+
+/// Generate an `option` module from the `serialize` and `deserialize`
+/// functions in the current module.
+macro_rules! option {
+    ($desc:literal) => {
+        #[doc = concat!("Serialize an [`Option<Uuid>`](Uuid) ", $desc)]
+        pub mod option {
+            use crate::Uuid;
+            use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            /// Adapts the parent module to the [`Serialize`] and [`Deserialize`]
+            /// traits, so serde can handle the [`Option`].
+            struct With(Uuid);
+
+            impl Serialize for With {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    super::serialize(&self.0, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for With {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    super::deserialize(deserializer).map(With)
+                }
+            }
+
+            /// Serialize an [`Option<Uuid>`](Uuid).
+            pub fn serialize<S: Serializer>(
+                uuid: &Option<Uuid>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                uuid.map(With).serialize(serializer)
+            }
+
+            /// Deserialize an [`Option<Uuid>`](Uuid).
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<Uuid>, D::Error> {
+                Ok(Option::<With>::deserialize(deserializer)?.map(|w| w.0))
+            }
+        }
+    };
+}
+
+/// Serialize a [`Uuid`] as a lowercase string without hyphens.
+///
+/// See [`Uuid::to_str_simple`].
+pub mod simple {
+    use crate::Uuid;
+    use ::serde::{Deserializer, Serializer};
+
+    /// Serialize a [`Uuid`].
+    pub fn serialize<S: Serializer>(uuid: &Uuid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(uuid.to_str_simple(&mut [0; 32]))
+    }
+
+    /// Deserialize a [`Uuid`].
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uuid, D::Error> {
+        super::deserialize_str(deserializer)
+    }
+
+    option!("as a lowercase string without hyphens.");
+}
+
+/// Serialize a [`Uuid`] as a lowercase braced string.
+///
+/// See [`Uuid::to_str_braced`].
+pub mod braced {
+    use crate::Uuid;
+    use ::serde::{Deserializer, Serializer};
+
+    /// Serialize a [`Uuid`].
+    pub fn serialize<S: Serializer>(uuid: &Uuid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(uuid.to_str_braced(&mut [0; 38]))
+    }
+
+    /// Deserialize a [`Uuid`].
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uuid, D::Error> {
+        super::deserialize_str(deserializer)
+    }
+
+    option!("as a lowercase braced string.");
+}
+
+/// Serialize a [`Uuid`] as a lowercase URN.
+///
+/// See [`Uuid::to_urn`].
+pub mod urn {
+    use crate::Uuid;
+    use ::serde::{Deserializer, Serializer};
+
+    /// Serialize a [`Uuid`].
+    pub fn serialize<S: Serializer>(uuid: &Uuid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(uuid.to_urn(&mut [0; 45]))
+    }
+
+    /// Deserialize a [`Uuid`].
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uuid, D::Error> {
+        super::deserialize_str(deserializer)
+    }
+
+    option!("as a lowercase URN.");
+}
+
+/// Serialize a [`Uuid`] as an uppercase hyphenated string.
+///
+/// See [`Uuid::to_str_upper`].
+pub mod uppercase {
+    use crate::Uuid;
+    use ::serde::{Deserializer, Serializer};
+
+    /// Serialize a [`Uuid`].
+    pub fn serialize<S: Serializer>(uuid: &Uuid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(uuid.to_str_upper(&mut [0; 36]))
+    }
+
+    /// Deserialize a [`Uuid`].
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uuid, D::Error> {
+        super::deserialize_str(deserializer)
+    }
+
+    option!("as an uppercase hyphenated string.");
+}
+
+/// Serialize a [`Uuid`] as 16 mixed-endian bytes.
+///
+/// See [`Uuid::to_bytes_me`].
+pub mod bytes_me {
+    use crate::Uuid;
+    use ::serde::{Deserializer, Serializer};
+
+    /// Serialize a [`Uuid`].
+    pub fn serialize<S: Serializer>(uuid: &Uuid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&uuid.to_bytes_me())
+    }
+
+    /// Deserialize a [`Uuid`].
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uuid, D::Error> {
+        deserializer
+            .deserialize_bytes(super::BytesVisitor)
+            .map(Uuid::from_bytes_me)
+    }
+
+    option!("as 16 mixed-endian bytes.");
+}
+
+/// Serialize a [`Uuid`] as a [`u128`](prim@u128).
+///
+/// See [`Uuid::to_u128`].
+pub mod u128 {
+    use crate::Uuid;
+    use ::serde::{Deserializer, Serializer};
+
+    /// Serialize a [`Uuid`].
+    pub fn serialize<S: Serializer>(uuid: &Uuid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u128(uuid.to_u128())
+    }
+
+    /// Deserialize a [`Uuid`].
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uuid, D::Error> {
+        deserializer.deserialize_u128(super::UuidVisitor)
+    }
+
+    option!("as a [`u128`](prim@u128).");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::serde::de::{value, IntoDeserializer};
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, assert_tokens, Compact, Configure, Token,
+    };
+
+    const UUID: &str = "662aa7c7-7598-4d56-8bcc-a72c30f998a2";
+    const RAW: [u8; 16] = [
+        102, 42, 167, 199, 117, 152, 77, 86, 139, 204, 167, 44, 48, 249, 152, 162,
+    ];
+    const RAW_ME: [u8; 16] = [
+        199, 167, 42, 102, 152, 117, 86, 77, 139, 204, 167, 44, 48, 249, 152, 162,
+    ];
+
+    #[test]
+    fn readable() {
+        let uuid = Uuid::from_bytes(RAW).readable();
+        assert_tokens(&uuid, &[Token::Str(UUID)]);
+
+        for s in &[
+            "662AA7C7-7598-4D56-8BCC-A72C30F998A2",
+            "662aa7c775984d568bcca72c30f998a2",
+            "{662aa7c7-7598-4d56-8bcc-a72c30f998a2}",
+            "urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2",
+        ] {
+            assert_de_tokens(&uuid, &[Token::Str(s)]);
+            assert_de_tokens(&uuid, &[Token::BorrowedStr(s)]);
+            assert_de_tokens(&uuid, &[Token::Bytes(s.as_bytes())]);
+        }
+        assert_de_tokens(&uuid, &[Token::Bytes(&RAW)]);
+
+        let mut tokens = vec![Token::Seq { len: Some(16) }];
+        tokens.extend(RAW.iter().map(|&b| Token::U8(b)));
+        tokens.push(Token::SeqEnd);
+        assert_de_tokens(&uuid, &tokens);
+
+        let de: value::U128Deserializer<value::Error> =
+            Uuid::from_bytes(RAW).to_u128().into_deserializer();
+        assert_eq!(Uuid::deserialize(de), Ok(Uuid::from_bytes(RAW)));
+        assert_de_tokens(&Uuid::from_u128(42).readable(), &[Token::U64(42)]);
+    }
+
+    #[test]
+    fn compact() {
+        let uuid = Uuid::from_bytes(RAW).compact();
+        assert_tokens(&uuid, &[Token::Bytes(&RAW)]);
+        assert_de_tokens(&uuid, &[Token::BorrowedBytes(&RAW)]);
+        assert_de_tokens(&uuid, &[Token::Str(UUID)]);
+    }
+
+    #[test]
+    fn errors() {
+        assert_de_tokens_error::<Compact<Uuid>>(
+            &[Token::Str("662aa7c7-7598-4d56-8bcc-a72c30f998ag")],
+            "invalid character 'g' at index 35",
+        );
+        assert_de_tokens_error::<Compact<Uuid>>(
+            &[Token::Bytes(b"662aa7c7-7598-4d56-8bcc-a72c30f998ag")],
+            "invalid character 'g' at index 35",
+        );
+        assert_de_tokens_error::<Compact<Uuid>>(
+            &[Token::Bytes(&RAW[..15])],
+            "invalid length 15, expected a UUID string, 16 bytes, or a u128",
+        );
+        assert_de_tokens_error::<Compact<Uuid>>(
+            &[Token::Seq { len: Some(1) }, Token::U8(1), Token::SeqEnd],
+            "invalid length 1, expected a UUID string, 16 bytes, or a u128",
+        );
+        assert_de_tokens_error::<Compact<Uuid>>(
+            &[Token::Bool(true)],
+            "invalid type: boolean `true`, expected a UUID string, 16 bytes, or a u128",
+        );
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Record {
+        #[serde(with = "crate::serde::simple")]
+        simple: Uuid,
+        #[serde(with = "crate::serde::braced")]
+        braced: Uuid,
+        #[serde(with = "crate::serde::urn")]
+        urn: Uuid,
+        #[serde(with = "crate::serde::uppercase")]
+        uppercase: Uuid,
+        #[serde(with = "crate::serde::bytes_me")]
+        bytes_me: Uuid,
+        #[serde(with = "crate::serde::u128")]
+        u128: Uuid,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Optional {
+        #[serde(with = "crate::serde::simple::option")]
+        simple: Option<Uuid>,
+        #[serde(with = "crate::serde::bytes_me::option")]
+        bytes_me: Option<Uuid>,
+    }
+
+    #[test]
+    fn with() {
+        let uuid = Uuid::from_bytes(RAW);
+        let record = Record {
+            simple: uuid,
+            braced: uuid,
+            urn: uuid,
+            uppercase: uuid,
+            bytes_me: uuid,
+            u128: uuid,
+        };
+        assert_eq!(uuid.to_bytes_me(), RAW_ME);
+        // serde_test has no 128-bit integer tokens.
+        let fields = [
+            Token::Struct {
+                name: "Record",
+                len: 6,
+            },
+            Token::Str("simple"),
+            Token::Str("662aa7c775984d568bcca72c30f998a2"),
+            Token::Str("braced"),
+            Token::Str("{662aa7c7-7598-4d56-8bcc-a72c30f998a2}"),
+            Token::Str("urn"),
+            Token::Str("urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2"),
+            Token::Str("uppercase"),
+            Token::Str("662AA7C7-7598-4D56-8BCC-A72C30F998A2"),
+            Token::Str("bytes_me"),
+            Token::Bytes(&RAW_ME),
+            Token::Str("u128"),
+        ];
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record);
+        assert!(json.contains(&format!("\"u128\":{}", uuid.to_u128())));
+
+        // String formats accept any format.
+        let mut tokens = fields.to_vec();
+        tokens[2] = Token::Str("662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+        tokens[4] = Token::Str("662AA7C775984D568BCCA72C30F998A2");
+        tokens.extend(&[
+            Token::Str("662aa7c7-7598-4d56-8bcc-a72c30f998a2"),
+            Token::StructEnd,
+        ]);
+        assert_de_tokens(&record, &tokens);
+
+        // Mixed-endian must not accept strings, which are never mixed-endian.
+        let mut tokens = fields.to_vec();
+        tokens[10] = Token::Str("662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+        assert_de_tokens_error::<Record>(
+            &tokens,
+            "invalid type: string \"662aa7c7-7598-4d56-8bcc-a72c30f998a2\", expected 16 bytes",
+        );
+
+        let optional = Optional {
+            simple: Some(uuid),
+            bytes_me: None,
+        };
+        assert_tokens(
+            &optional,
+            &[
+                Token::Struct {
+                    name: "Optional",
+                    len: 2,
+                },
+                Token::Str("simple"),
+                Token::Some,
+                Token::Str("662aa7c775984d568bcca72c30f998a2"),
+                Token::Str("bytes_me"),
+                Token::None,
+                Token::StructEnd,
+            ],
+        );
+        let optional = Optional {
+            simple: None,
+            bytes_me: Some(uuid),
+        };
+        assert_tokens(
+            &optional,
+            &[
+                Token::Struct {
+                    name: "Optional",
+                    len: 2,
+                },
+                Token::Str("simple"),
+                Token::None,
+                Token::Str("bytes_me"),
+                Token::Some,
+                Token::Bytes(&RAW_ME),
+                Token::StructEnd,
+            ],
+        );
+        let json = serde_json::to_string(&optional).unwrap();
+        assert_eq!(serde_json::from_str::<Optional>(&json).unwrap(), optional);
+    }
+}